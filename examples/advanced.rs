@@ -116,4 +116,21 @@ fn main() {
     if let Ok(chart) = plot_with_config(&data_small, config_small) {
         println!("{}\n", chart);
     }
-}
\ No newline at end of file
+
+    // Example 13: Scatter plot
+    println!("13. Scatter Plot (latency vs payload size):");
+    let samples: Vec<(f64, f64)> = (0..60)
+        .map(|i| {
+            let size = (i * 37 % 100) as f64;
+            let latency = 5.0 + size * 0.4 + ((i * 13) % 9) as f64;
+            (size, latency)
+        })
+        .collect();
+    let config_scatter = Config::new()
+        .with_height(12)
+        .with_width(60)
+        .with_label_format("{:.0}".to_string());
+    if let Ok(chart) = scatter_with_config(&samples, config_scatter) {
+        println!("{}\n", chart);
+    }
+}
@@ -15,6 +15,16 @@ fn main() {
     println!("Series 2 (Decreasing):\n{}\n", plot(&series2));
     println!("Combined View:\n{}\n", plot_multiple(&[&series1, &series2]));
 
+    let colored = Config::new()
+        .with_height(10)
+        .with_width(40)
+        .with_series_styles(vec![Color::Green.into(), Color::Red.into()])
+        .with_series_names(vec!["Increasing".to_string(), "Decreasing".to_string()])
+        .with_legend(LegendPosition::Bottom);
+    if let Ok(chart) = plot_multiple_with_config(&[&series1, &series2], colored) {
+        println!("Combined View (colored):\n{}\n", chart);
+    }
+
     // Example 2: Sine and Cosine
     println!("2. Sine and Cosine Waves:");
     let sine = generate_sine(60, 2.0, 0.0);
@@ -139,7 +139,20 @@ fn main() {
             .with_height(12)
             .with_width(60)
             .with_min(0.0)
-            .with_max(100.0);
+            .with_max(100.0)
+            .with_area(AreaBaseline::Min)
+            .with_series_styles(vec![Color::Cyan.into()])
+            .with_dim_fill(true)
+            .with_reference_line(
+                ReferenceLine::new(80.0)
+                    .with_label("80%")
+                    .with_style(Color::Red.into()),
+            )
+            .with_reference_line(
+                ReferenceLine::new(60.0)
+                    .with_label("60%")
+                    .with_style(Color::Yellow.into()),
+            );
         
         let chart = plot_with_config(&cpu_history, config).unwrap_or_default();
         
@@ -180,4 +180,30 @@ fn main() {
     println!("  Max:   ${:.2}", max);
     println!("  Range: ${:.2}", range);
     println!("  Volatility: {:.2}%", (range / mean) * 100.0);
-}
\ No newline at end of file
+
+    // Example 11: Candlesticks
+    println!("\n11. Daily Candlesticks (OHLC):");
+    let mut open = 100.0;
+    let candles: Vec<(f64, f64, f64, f64)> = (0..16)
+        .map(|x| {
+            let close = open + ((x * 7) % 11) as f64 - 5.0;
+            let high = open.max(close) + ((x * 3) % 4) as f64;
+            let low = open.min(close) - ((x * 5) % 3) as f64;
+            let candle = (open, high, low, close);
+            open = close;
+            candle
+        })
+        .collect();
+
+    let config = Config::new()
+        .with_height(15)
+        .with_width(50)
+        .with_bar_width(1)
+        .with_bar_gap(2)
+        .with_label_format("{:.1}".to_string())
+        .with_candle_colors(Color::Green, Color::Red);
+
+    if let Ok(chart) = candlestick_with_config(&candles, config) {
+        println!("{}", chart);
+    }
+}
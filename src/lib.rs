@@ -28,6 +28,7 @@
 //! ```
 
 use std::fmt;
+use std::sync::Arc;
 
 /// Error types for the library
 #[derive(Debug, Clone, PartialEq)]
@@ -35,6 +36,8 @@ pub enum ChartError {
     EmptyData,
     InvalidRange,
     InvalidDimensions,
+    InvalidFormat(String),
+    NonPositiveLogValue,
 }
 
 impl fmt::Display for ChartError {
@@ -43,6 +46,8 @@ impl fmt::Display for ChartError {
             ChartError::EmptyData => write!(f, "Cannot plot empty data"),
             ChartError::InvalidRange => write!(f, "Invalid min/max range"),
             ChartError::InvalidDimensions => write!(f, "Invalid chart dimensions"),
+            ChartError::InvalidFormat(reason) => write!(f, "Invalid label format {}", reason),
+            ChartError::NonPositiveLogValue => write!(f, "Cannot plot non-positive values on a log scale"),
         }
     }
 }
@@ -68,10 +73,286 @@ pub struct Config {
     pub show_labels: bool,
     /// Number of Y-axis label ticks
     pub label_ticks: usize,
+    /// How Y-axis label rows and values are chosen
+    pub tick_mode: TickMode,
+    /// With nice ticks, widen auto-calculated min/max out to the nearest tick
+    pub nice_bounds: bool,
+    /// Mapping from values to rows
+    pub scale: Scale,
+    /// On a log scale, drop non-positive values as gaps instead of failing
+    pub log_gaps: bool,
     /// Format string for Y-axis labels
     pub label_format: String,
+    /// Custom Y-axis label callback, used instead of `label_format`
+    pub label_formatter: Option<LabelFormatter>,
     /// Characters to use for drawing
     pub symbols: Symbols,
+    /// How series are drawn onto the canvas
+    pub render_mode: RenderMode,
+    /// Draw lines as steps instead of joining samples directly (no steps if None)
+    pub step: Option<StepMode>,
+    /// Reduce series longer than the plot to fit its width (truncated if None)
+    pub downsample: Option<Downsample>,
+    /// Stretch series shorter than the plot across its width (left-aligned if None)
+    pub stretch: Option<Interpolation>,
+    /// How missing (NaN or infinite) values are drawn
+    pub gap_policy: GapPolicy,
+    /// Add a line below the chart counting points outside a fixed min/max (off by default)
+    pub clip_report: bool,
+    /// Horizontal lines at fixed Y values, drawn behind the series
+    pub reference_lines: Vec<ReferenceLine>,
+    /// Style for each series, by index (plain if missing)
+    pub series_styles: Vec<Style>,
+    /// Fill the area between each line and this baseline (no fill if None)
+    pub area: Option<AreaBaseline>,
+    /// Draw area fills dimmed instead of in the full series style
+    pub dim_fill: bool,
+    /// Style for the Y-axis line
+    pub axis_style: Style,
+    /// Style for the Y-axis labels
+    pub label_style: Style,
+    /// Display name for each series, by index ("Series N" if missing)
+    pub series_names: Vec<String>,
+    /// Legend placement (no legend if None)
+    pub legend: Option<LegendPosition>,
+    /// Draw an X axis with tick marks below the chart
+    pub show_x_axis: bool,
+    /// Number of data points between X-axis ticks (columns in scatter plots)
+    pub x_label_interval: usize,
+    /// Labels for X-axis ticks, by data index (the index itself if empty)
+    pub x_labels: Vec<String>,
+    /// Unix timestamp in seconds for each data index, used for X-axis labels
+    pub x_timestamps: Vec<i64>,
+    /// Custom X-axis label callback, given the data index or timestamp
+    pub x_label_formatter: Option<LabelFormatter>,
+    /// Minimum X value (scatter plots; auto-calculated if None)
+    pub x_min: Option<f64>,
+    /// Maximum X value (scatter plots; auto-calculated if None)
+    pub x_max: Option<f64>,
+    /// Width of each bar in columns (bar and candlestick charts)
+    pub bar_width: usize,
+    /// Blank columns between bars (bar and candlestick charts)
+    pub bar_gap: usize,
+    /// Category ordering (horizontal bar charts)
+    pub bar_sort: BarSort,
+    /// Maximum category label width; longer names are truncated (horizontal bar charts)
+    pub category_width: Option<usize>,
+}
+
+/// Strategy for placing Y-axis labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TickMode {
+    /// Label every `height / label_ticks` rows with whatever value the row maps to
+    #[default]
+    Rows,
+    /// Label human-friendly values (1, 2 or 5 × 10^n steps) on their closest rows
+    Nice,
+}
+
+/// How series are drawn onto the canvas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// Connected lines using the box-drawing glyphs from `Symbols`
+    #[default]
+    Lines,
+    /// Unicode braille dots: 2 samples per column and 4 levels per row
+    Braille,
+    /// Half-block line (▀ ▄ █): 2 levels per row
+    HalfBlock,
+    /// Half-block columns filled down to the bottom of the chart
+    HalfBlockFilled,
+}
+
+impl RenderMode {
+    /// Number of data points that share one canvas column
+    fn samples_per_column(self) -> usize {
+        match self {
+            RenderMode::Lines | RenderMode::HalfBlock | RenderMode::HalfBlockFilled => 1,
+            RenderMode::Braille => 2,
+        }
+    }
+}
+
+/// Y-axis scale
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Scale {
+    /// Values map linearly to rows
+    #[default]
+    Linear,
+    /// Values map to rows by their logarithm in the given base (which must be above 1)
+    Log(f64),
+}
+
+impl Scale {
+    /// Map a data value into scale space
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Scale::Linear => value,
+            Scale::Log(base) => value.log(base),
+        }
+    }
+
+    /// Map a scale-space value back to a data value
+    fn invert(self, value: f64) -> f64 {
+        match self {
+            Scale::Linear => value,
+            Scale::Log(base) => base.powf(value),
+        }
+    }
+}
+
+/// Callback that turns an axis value into label text
+///
+/// Wraps the closure in an `Arc` so `Config` stays `Clone` and `Debug`.
+#[derive(Clone)]
+pub struct LabelFormatter(Arc<dyn Fn(f64) -> String + Send + Sync>);
+
+impl LabelFormatter {
+    /// Wrap a formatting closure
+    pub fn new<F>(formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        Self(Arc::new(formatter))
+    }
+
+    /// Format a value with the wrapped closure
+    pub fn format(&self, value: f64) -> String {
+        (self.0)(value)
+    }
+}
+
+impl LabelFormatter {
+    /// SI-prefixed labels (n, µ, m, k, M, G, T) followed by `unit`, e.g. `"1.50 kB/s"`
+    ///
+    /// `precision` is capped at `u16::MAX` decimals.
+    pub fn si(unit: &str, precision: usize) -> Self {
+        let unit = unit.to_string();
+        Self::new(move |v| format_scaled(v, 1000.0, &SI_PREFIXES, &unit, precision))
+    }
+
+    /// IEC binary-prefixed labels (Ki, Mi, Gi, ...) followed by `unit`, e.g. `"3.00 MiB"`
+    ///
+    /// `precision` is capped at `u16::MAX` decimals.
+    pub fn binary(unit: &str, precision: usize) -> Self {
+        let unit = unit.to_string();
+        Self::new(move |v| format_scaled(v, 1024.0, &BINARY_PREFIXES, &unit, precision))
+    }
+}
+
+impl fmt::Debug for LabelFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("LabelFormatter(..)")
+    }
+}
+
+/// Where the legend is drawn relative to the plot area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendPosition {
+    /// Above the chart, entries wrapped to the chart width
+    Top,
+    /// Below the chart, entries wrapped to the chart width
+    Bottom,
+    /// In a column to the right; the plot area shrinks to make room, and
+    /// entries beyond the plot's rows are summarised as "+N more"
+    Right,
+}
+
+/// Where the riser between two samples goes in a step chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepMode {
+    /// A value takes effect from the previous sample: the riser is in the previous sample's column
+    Before,
+    /// A value holds until the next sample: the riser is in the next sample's column
+    #[default]
+    After,
+    /// The riser is halfway between the two samples (the next column if they are adjacent)
+    Mid,
+}
+
+/// How series longer than the plot width are reduced to fit
+///
+/// Samples are split into one bucket per plotted point (two for `MinMax`).
+/// Buckets are laid out for the longest series, so shorter series stay
+/// aligned with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Downsample {
+    /// Keep every n-th sample
+    EveryNth,
+    /// Mean of the finite samples in each bucket
+    Mean,
+    /// Minimum and maximum of each bucket, in the order they occur
+    MinMax,
+    /// Largest-Triangle-Three-Buckets: keep the sample in each bucket that best preserves the shape
+    Lttb,
+}
+
+/// How values between samples are filled in when a series is stretched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Repeat the closest sample
+    Nearest,
+    /// Straight lines between samples
+    #[default]
+    Linear,
+    /// Smooth curve that never overshoots the samples (Fritsch–Butland tangents)
+    MonotoneCubic,
+}
+
+/// How a line is drawn across missing (NaN or infinite) values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapPolicy {
+    /// End the line at the gap and start a fresh segment after it
+    #[default]
+    Break,
+    /// Join the samples either side of the gap with `symbols.gap_bridge`
+    /// (every other dot in braille mode; half-block modes break instead)
+    Bridge,
+    /// Break the line and put `symbols.gap_mark` in the bottom row of each missing column
+    Mark,
+}
+
+/// A horizontal reference line at a fixed Y value, such as a threshold
+///
+/// Drawn with `symbols.reference` on the row the value maps to, behind the
+/// series. A label is shown to the right of the plot on that row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceLine {
+    /// Y value the line is drawn at
+    pub value: f64,
+    /// Text shown to the right of the plot (none if None)
+    pub label: Option<String>,
+    /// Style of the line and its label
+    pub style: Style,
+}
+
+impl ReferenceLine {
+    /// Create an unlabelled, plain reference line at `value`
+    pub fn new(value: f64) -> Self {
+        Self { value, label: None, style: Style::default() }
+    }
+
+    /// Label the line on the right of the plot, e.g. `"SLO 80%"`
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Set the style of the line and its label
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Baseline that area charts fill down (or up) to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AreaBaseline {
+    /// The bottom of the chart
+    #[default]
+    Min,
+    /// The row for zero, clamped to the chart
+    Zero,
 }
 
 /// Symbols used for drawing the chart
@@ -86,6 +367,18 @@ pub struct Symbols {
     pub axis_vertical: char,
     pub axis_corner: char,
     pub axis_bottom: char,
+    pub axis_horizontal: char,
+    pub axis_origin: char,
+    pub bar: char,
+    pub candle_body: char,
+    pub candle_wick: char,
+    pub marker: char,
+    pub area_fill: char,
+    pub gap_bridge: char,
+    pub gap_mark: char,
+    pub overflow: char,
+    pub underflow: char,
+    pub reference: char,
 }
 
 impl Default for Symbols {
@@ -100,6 +393,18 @@ impl Default for Symbols {
             axis_vertical: '│',
             axis_corner: '┤',
             axis_bottom: '┴',
+            axis_horizontal: '─',
+            axis_origin: '└',
+            bar: '█',
+            candle_body: '┃',
+            candle_wick: '│',
+            marker: '●',
+            area_fill: '░',
+            gap_bridge: '┄',
+            gap_mark: '×',
+            overflow: '▲',
+            underflow: '▼',
+            reference: '╌',
         }
     }
 }
@@ -117,8 +422,224 @@ impl Symbols {
             axis_vertical: '|',
             axis_corner: '|',
             axis_bottom: '+',
+            axis_horizontal: '-',
+            axis_origin: '+',
+            bar: '#',
+            candle_body: '#',
+            candle_wick: '|',
+            marker: '*',
+            area_fill: ':',
+            gap_bridge: '.',
+            gap_mark: 'x',
+            overflow: '^',
+            underflow: 'v',
+            reference: '.',
+        }
+    }
+}
+
+/// Terminal colors, emitted as ANSI SGR foreground sequences
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// Color from the 256-color palette
+    Ansi256(u8),
+    /// 24-bit truecolor
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// SGR parameters selecting this color as foreground
+    fn sgr(&self) -> String {
+        match *self {
+            Color::Black => "30".to_string(),
+            Color::Red => "31".to_string(),
+            Color::Green => "32".to_string(),
+            Color::Yellow => "33".to_string(),
+            Color::Blue => "34".to_string(),
+            Color::Magenta => "35".to_string(),
+            Color::Cyan => "36".to_string(),
+            Color::White => "37".to_string(),
+            Color::BrightBlack => "90".to_string(),
+            Color::BrightRed => "91".to_string(),
+            Color::BrightGreen => "92".to_string(),
+            Color::BrightYellow => "93".to_string(),
+            Color::BrightBlue => "94".to_string(),
+            Color::BrightMagenta => "95".to_string(),
+            Color::BrightCyan => "96".to_string(),
+            Color::BrightWhite => "97".to_string(),
+            Color::Ansi256(n) => format!("38;5;{}", n),
+            Color::Rgb(r, g, b) => format!("38;2;{};{};{}", r, g, b),
+        }
+    }
+}
+
+/// Text style for series glyphs, the axis and labels
+///
+/// The default style is plain and produces no escape sequences at all.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    /// Foreground color
+    pub fg: Option<Color>,
+    /// Bold / increased intensity
+    pub bold: bool,
+    /// Dim / decreased intensity
+    pub dim: bool,
+}
+
+impl Style {
+    /// Create a plain style
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the foreground color
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Render in bold
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Render dimmed
+    pub fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    /// Whether this style emits no escape sequences
+    pub fn is_plain(&self) -> bool {
+        self.fg.is_none() && !self.bold && !self.dim
+    }
+
+    /// Wrap `text` in the SGR sequences for this style
+    pub fn paint(&self, text: &str) -> String {
+        if self.is_plain() || text.is_empty() {
+            return text.to_string();
+        }
+
+        let mut params = Vec::new();
+        if self.bold {
+            params.push("1".to_string());
+        }
+        if self.dim {
+            params.push("2".to_string());
+        }
+        if let Some(color) = self.fg {
+            params.push(color.sgr());
+        }
+
+        format!("\x1b[{}m{}\x1b[0m", params.join(";"), text)
+    }
+}
+
+impl From<Color> for Style {
+    fn from(color: Color) -> Self {
+        Style::new().fg(color)
+    }
+}
+
+/// Unit of the timestamps passed to [`Config::with_timestamps`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Seconds,
+    Milliseconds,
+}
+
+impl TimeUnit {
+    fn to_seconds(self, value: i64) -> i64 {
+        match self {
+            TimeUnit::Seconds => value,
+            TimeUnit::Milliseconds => value.div_euclid(1000),
+        }
+    }
+}
+
+/// Resolution of time-based X-axis labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeGranularity {
+    /// `HH:MM:SS`
+    Seconds,
+    /// `HH:MM`
+    Minutes,
+    /// `MM-DD HH:00`
+    Hours,
+    /// `MM-DD`
+    Days,
+    /// `YYYY-MM`
+    Months,
+    /// `YYYY`
+    Years,
+}
+
+impl TimeGranularity {
+    /// Pick a granularity for a time span given in seconds
+    pub fn for_span(span: u64) -> Self {
+        const MINUTE: u64 = 60;
+        const HOUR: u64 = 60 * MINUTE;
+        const DAY: u64 = 24 * HOUR;
+
+        match span {
+            s if s <= 5 * MINUTE => TimeGranularity::Seconds,
+            s if s <= 12 * HOUR => TimeGranularity::Minutes,
+            s if s <= 3 * DAY => TimeGranularity::Hours,
+            s if s <= 90 * DAY => TimeGranularity::Days,
+            s if s <= 3 * 365 * DAY => TimeGranularity::Months,
+            _ => TimeGranularity::Years,
         }
     }
+
+    /// Format a Unix timestamp (seconds, UTC) at this granularity
+    pub fn format(&self, timestamp: i64) -> String {
+        let days = timestamp.div_euclid(86_400);
+        let secs = timestamp.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+        let (hour, minute, second) = (secs / 3600, secs % 3600 / 60, secs % 60);
+
+        match self {
+            TimeGranularity::Seconds => format!("{:02}:{:02}:{:02}", hour, minute, second),
+            TimeGranularity::Minutes => format!("{:02}:{:02}", hour, minute),
+            TimeGranularity::Hours => format!("{:02}-{:02} {:02}:00", month, day, hour),
+            TimeGranularity::Days => format!("{:02}-{:02}", month, day),
+            TimeGranularity::Months => format!("{}-{:02}", year, month),
+            TimeGranularity::Years => format!("{}", year),
+        }
+    }
+}
+
+/// Convert days since 1970-01-01 into a (year, month, day) civil date
+///
+/// Howard Hinnant's `civil_from_days` algorithm (proleptic Gregorian calendar).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
 }
 
 impl Default for Config {
@@ -131,8 +652,38 @@ impl Default for Config {
             max: None,
             show_labels: true,
             label_ticks: 5,
+            tick_mode: TickMode::Rows,
+            nice_bounds: false,
+            scale: Scale::Linear,
+            log_gaps: false,
             label_format: "{:.2}".to_string(),
+            label_formatter: None,
             symbols: Symbols::default(),
+            render_mode: RenderMode::Lines,
+            step: None,
+            downsample: None,
+            stretch: None,
+            gap_policy: GapPolicy::Break,
+            clip_report: false,
+            reference_lines: Vec::new(),
+            series_styles: Vec::new(),
+            area: None,
+            dim_fill: false,
+            axis_style: Style::default(),
+            label_style: Style::default(),
+            series_names: Vec::new(),
+            legend: None,
+            show_x_axis: false,
+            x_label_interval: 10,
+            x_labels: Vec::new(),
+            x_timestamps: Vec::new(),
+            x_label_formatter: None,
+            x_min: None,
+            x_max: None,
+            bar_width: 1,
+            bar_gap: 1,
+            bar_sort: BarSort::None,
+            category_width: None,
         }
     }
 }
@@ -185,12 +736,101 @@ impl Config {
         self
     }
 
+    /// Use "nice" Y-axis ticks (1-2-5 × 10^n steps)
+    ///
+    /// `label_ticks` becomes the approximate number of intervals. With
+    /// `extend_bounds`, auto-calculated min/max are widened to the nearest
+    /// tick so the first and last rows carry round labels too.
+    pub fn with_nice_ticks(mut self, extend_bounds: bool) -> Self {
+        self.tick_mode = TickMode::Nice;
+        self.nice_bounds = extend_bounds;
+        self
+    }
+
+    /// Use a base-10 logarithmic Y axis
+    pub fn with_log_scale(self) -> Self {
+        self.with_log_base(10.0)
+    }
+
+    /// Use a logarithmic Y axis with an arbitrary base
+    ///
+    /// Labels are placed on whole powers of the base when at least two are
+    /// visible. Non-positive values fail with
+    /// [`ChartError::NonPositiveLogValue`] unless `with_log_gaps(true)` is set.
+    pub fn with_log_base(mut self, base: f64) -> Self {
+        self.scale = Scale::Log(base);
+        self
+    }
+
+    /// On a log scale, skip non-positive values as gaps instead of failing
+    pub fn with_log_gaps(mut self, gaps: bool) -> Self {
+        self.log_gaps = gaps;
+        self
+    }
+
     /// Set the label format string
+    ///
+    /// The format is literal text around one Rust-style placeholder, e.g.
+    /// `"{:.3}"`, `"{:+.1}"`, `"{:.2e}"`, `"{:,.0}"`, `"${:.2}"` or `"{:.1}%"`.
     pub fn with_label_format(mut self, format: String) -> Self {
         self.label_format = format;
         self
     }
 
+    /// Format Y-axis labels with a custom callback instead of `label_format`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rasciichart::Config;
+    ///
+    /// let config = Config::new().with_label_formatter(|v| format!("{:.0} ms", v));
+    /// ```
+    pub fn with_label_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        self.label_formatter = Some(LabelFormatter::new(formatter));
+        self
+    }
+
+    /// Format X-axis labels with a custom callback
+    ///
+    /// The callback receives the data index, or the Unix timestamp in
+    /// seconds when timestamps are set. Explicit `x_labels` still win.
+    pub fn with_x_label_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        self.x_label_formatter = Some(LabelFormatter::new(formatter));
+        self
+    }
+
+    /// Label the Y axis with SI prefixes and a unit suffix, e.g. `"req/s"`
+    pub fn with_si_labels(mut self, unit: &str, precision: usize) -> Self {
+        self.label_formatter = Some(LabelFormatter::si(unit, precision));
+        self
+    }
+
+    /// Label the Y axis with IEC binary prefixes and a unit suffix, e.g. `"B"`
+    pub fn with_binary_labels(mut self, unit: &str, precision: usize) -> Self {
+        self.label_formatter = Some(LabelFormatter::binary(unit, precision));
+        self
+    }
+
+    /// Format a Y-axis value for display
+    fn format_label(&self, value: f64) -> String {
+        match &self.label_formatter {
+            Some(formatter) => formatter.format(value),
+            None => format_value(value, &self.label_format),
+        }
+    }
+
+    /// Format a Y-axis position given in scale space
+    fn format_axis_value(&self, value: f64) -> String {
+        self.format_label(self.scale.invert(value))
+    }
+
     /// Use ASCII-only symbols
     pub fn with_ascii_symbols(mut self) -> Self {
         self.symbols = Symbols::ascii();
@@ -203,488 +843,4083 @@ impl Config {
         self
     }
 
-    /// Validate the configuration
-    pub fn validate(&self) -> Result<()> {
-        if self.height == 0 || self.width == 0 {
-            return Err(ChartError::InvalidDimensions);
-        }
-        if let (Some(min), Some(max)) = (self.min, self.max) {
-            if min >= max {
-                return Err(ChartError::InvalidRange);
-            }
-        }
-        Ok(())
+    /// Set how series are drawn onto the canvas
+    pub fn with_render_mode(mut self, mode: RenderMode) -> Self {
+        self.render_mode = mode;
+        self
     }
-}
 
-/// Main plotting function - creates an ASCII chart from a data series
-///
-/// # Arguments
-///
-/// * `series` - A slice of f64 values to plot
-/// * `config` - Configuration for chart rendering
-///
-/// # Returns
-///
-/// A String containing the rendered ASCII chart
-///
-/// # Example
-///
-/// ```rust
-/// use rasciichart::{plot_with_config, Config};
-///
-/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-/// let config = Config::new().with_height(15).with_width(60);
-/// let chart = plot_with_config(&data, config).unwrap();
-/// println!("{}", chart);
-/// ```
-pub fn plot_with_config(series: &[f64], config: Config) -> Result<String> {
-    config.validate()?;
+    /// Draw lines as steps, with horizontal runs and vertical risers
+    ///
+    /// Applies to `RenderMode::Lines`.
+    pub fn with_step(mut self, mode: StepMode) -> Self {
+        self.step = Some(mode);
+        self
+    }
 
-    if series.is_empty() {
-        return Err(ChartError::EmptyData);
+    /// Downsample series that are longer than the plot width
+    pub fn with_downsample(mut self, downsample: Downsample) -> Self {
+        self.downsample = Some(downsample);
+        self
     }
 
-    if series.len() == 1 {
-        return Ok(format_value(series[0], &config.label_format));
+    /// Stretch series shorter than the plot so they span its full width
+    ///
+    /// Samples are spread evenly across the plot and the columns between
+    /// them are filled with `interpolation`. Step charts hold each value
+    /// according to their `StepMode` instead.
+    pub fn with_stretch(mut self, interpolation: Interpolation) -> Self {
+        self.stretch = Some(interpolation);
+        self
     }
 
-    // Filter out non-finite values for min/max calculation
-    let finite_values: Vec<f64> = series.iter()
-        .copied()
-        .filter(|v| v.is_finite())
-        .collect();
+    /// Set how missing values are drawn
+    pub fn with_gap_policy(mut self, policy: GapPolicy) -> Self {
+        self.gap_policy = policy;
+        self
+    }
 
-    if finite_values.is_empty() {
-        return Err(ChartError::InvalidRange);
+    /// Set the glyph that marks missing columns with `GapPolicy::Mark`
+    pub fn with_gap_mark(mut self, mark: char) -> Self {
+        self.symbols.gap_mark = mark;
+        self
     }
 
-    // Determine min and max
-    let min = config.min.unwrap_or_else(|| {
-        finite_values.iter().copied().fold(f64::INFINITY, f64::min)
-    });
-    
-    let max = config.max.unwrap_or_else(|| {
-        finite_values.iter().copied().fold(f64::NEG_INFINITY, f64::max)
-    });
+    /// Set whether to report points outside a fixed min/max below the chart
+    ///
+    /// Off by default. Clipped points are marked with `symbols.overflow` /
+    /// `symbols.underflow` either way.
+    pub fn with_clip_report(mut self, report: bool) -> Self {
+        self.clip_report = report;
+        self
+    }
 
-    if !min.is_finite() || !max.is_finite() {
-        return Err(ChartError::InvalidRange);
+    /// Add a horizontal reference line
+    pub fn with_reference_line(mut self, line: ReferenceLine) -> Self {
+        self.reference_lines.push(line);
+        self
     }
 
-    // Handle case where all values are the same
-    if (max - min).abs() < f64::EPSILON {
-        return Ok(format_value(min, &config.label_format));
+    /// Set the dashed glyph used to draw reference lines
+    pub fn with_reference_char(mut self, dash: char) -> Self {
+        self.symbols.reference = dash;
+        self
     }
 
-    let range = max - min;
-    let height = config.height;
-    let ratio = (height as f64) / range;
+    /// Set the style of each series, in the order the series are passed
+    pub fn with_series_styles(mut self, styles: Vec<Style>) -> Self {
+        self.series_styles = styles;
+        self
+    }
 
-    // Initialize canvas - no extra width needed
-    let mut canvas: Vec<Vec<char>> = vec![vec![' '; config.width]; height + 1];
+    /// Fill the area under each line down to `baseline`
+    ///
+    /// Applies to `RenderMode::Lines`; the fill uses `symbols.area_fill`
+    /// and is drawn behind every line.
+    pub fn with_area(mut self, baseline: AreaBaseline) -> Self {
+        self.area = Some(baseline);
+        self
+    }
 
-    // Plot the line - SKIP x=0 (reserved for axis separator)
-    let mut y0: Option<usize> = None;
+    /// Set the glyph used for area fills, e.g. '░', '▒', '▓' or '█'
+    pub fn with_fill_char(mut self, fill: char) -> Self {
+        self.symbols.area_fill = fill;
+        self
+    }
 
-    for (x, &value) in series.iter().enumerate().take(config.width.saturating_sub(1)) {
-        if !value.is_finite() {
-            continue;
-        }
+    /// Set whether area fills are drawn dimmer than their line
+    pub fn with_dim_fill(mut self, dim: bool) -> Self {
+        self.dim_fill = dim;
+        self
+    }
 
-        let y = ((max - value) * ratio).round() as usize;
-        let y = y.min(height);
-        
-        let plot_x = x + 1; // Start from x=1, skip x=0
+    /// Color rising and falling candles in candlestick charts
+    ///
+    /// Shorthand for `series_styles` `[up, down]`.
+    pub fn with_candle_colors(mut self, up: Color, down: Color) -> Self {
+        self.series_styles = vec![up.into(), down.into()];
+        self
+    }
 
-        if let Some(y_prev) = y0 {
-            if y == y_prev {
-                // Horizontal line
-                canvas[y][plot_x] = config.symbols.horizontal;
-            } else {
-                // Vertical movement
-                let (y_start, y_end) = if y_prev < y {
-                    (y_prev, y)
-                } else {
-                    (y, y_prev)
-                };
+    /// Set the style of the Y-axis line
+    pub fn with_axis_style(mut self, style: Style) -> Self {
+        self.axis_style = style;
+        self
+    }
 
-                // Draw vertical connection
-                for y_line in y_start..=y_end {
-                    if y_line == y_prev {
-                        if y_prev < y {
-                            canvas[y_line][plot_x] = config.symbols.top_right;
-                        } else {
-                            canvas[y_line][plot_x] = config.symbols.bottom_right;
-                        }
-                    } else if y_line == y {
-                        if y_prev < y {
-                            canvas[y_line][plot_x] = config.symbols.bottom_left;
-                        } else {
-                            canvas[y_line][plot_x] = config.symbols.top_left;
-                        }
-                    } else {
-                        canvas[y_line][plot_x] = config.symbols.vertical;
-                    }
-                }
-            }
-        } else {
-            // First point
-            canvas[y][plot_x] = config.symbols.vertical;
-        }
+    /// Set the style of the Y-axis labels
+    pub fn with_label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
 
-        y0 = Some(y);
+    /// Set the display name of each series, in the order the series are passed
+    pub fn with_series_names(mut self, names: Vec<String>) -> Self {
+        self.series_names = names;
+        self
     }
 
-    // Build output with Y-axis labels
-    let mut lines = Vec::new();
-    
-    if config.show_labels {
-        let label_width = format_value(max, &config.label_format).len()
-            .max(format_value(min, &config.label_format).len());
+    /// Show a legend at the given position
+    pub fn with_legend(mut self, position: LegendPosition) -> Self {
+        self.legend = Some(position);
+        self
+    }
 
-        for (idx, row) in canvas.iter().enumerate() {
-            let y_value = max - (idx as f64 * range / height as f64);
-            
-            // Determine if this row should have a label
-            let label = if idx == 0 {
-                format!("{:>width$}", format_value(max, &config.label_format), width = label_width)
-            } else if idx == height {
-                format!("{:>width$}", format_value(min, &config.label_format), width = label_width)
-            } else if config.label_ticks > 0 && height >= config.label_ticks {
-                let step = height / config.label_ticks;
-                if step > 0 && idx % step == 0 {
-                    format!("{:>width$}", format_value(y_value, &config.label_format), width = label_width)
-                } else {
-                    " ".repeat(label_width)
+    /// Set whether to draw the X axis
+    pub fn with_x_axis(mut self, show: bool) -> Self {
+        self.show_x_axis = show;
+        self
+    }
+
+    /// Set the number of data points between X-axis ticks
+    pub fn with_x_label_interval(mut self, interval: usize) -> Self {
+        self.x_label_interval = interval;
+        self
+    }
+
+    /// Set the X-axis tick labels, one per data index
+    pub fn with_x_labels(mut self, labels: Vec<String>) -> Self {
+        self.x_labels = labels;
+        self.show_x_axis = true;
+        self
+    }
+
+    /// Set a Unix timestamp for each data index and label the X axis with times
+    ///
+    /// Tick labels are rendered in UTC with a granularity chosen from the
+    /// visible time span (see [`TimeGranularity::for_span`]). Explicit
+    /// `x_labels` take precedence over timestamps.
+    pub fn with_timestamps(mut self, timestamps: Vec<i64>, unit: TimeUnit) -> Self {
+        self.x_timestamps = timestamps.into_iter().map(|t| unit.to_seconds(t)).collect();
+        self.show_x_axis = true;
+        self
+    }
+
+    /// Time label granularity for the first `visible` data points, if timestamps are set
+    fn time_granularity(&self, visible: usize) -> Option<TimeGranularity> {
+        let visible = &self.x_timestamps[..visible.min(self.x_timestamps.len())];
+        let first = visible.iter().min()?;
+        let last = visible.iter().max()?;
+        Some(TimeGranularity::for_span(last.abs_diff(*first)))
+    }
+
+    /// Set the minimum X value for scatter plots
+    pub fn with_x_min(mut self, min: f64) -> Self {
+        self.x_min = Some(min);
+        self
+    }
+
+    /// Set the maximum X value for scatter plots
+    pub fn with_x_max(mut self, max: f64) -> Self {
+        self.x_max = Some(max);
+        self
+    }
+
+    /// Set the glyph used for scatter plot points
+    pub fn with_marker(mut self, marker: char) -> Self {
+        self.symbols.marker = marker;
+        self
+    }
+
+    /// Set the width of each bar in columns
+    pub fn with_bar_width(mut self, width: usize) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    /// Set the number of blank columns between bars
+    pub fn with_bar_gap(mut self, gap: usize) -> Self {
+        self.bar_gap = gap;
+        self
+    }
+
+    /// Set the category ordering for horizontal bar charts
+    pub fn with_bar_sort(mut self, sort: BarSort) -> Self {
+        self.bar_sort = sort;
+        self
+    }
+
+    /// Truncate horizontal bar chart category labels to `width` characters
+    pub fn with_category_width(mut self, width: usize) -> Self {
+        self.category_width = Some(width);
+        self
+    }
+
+    /// Label for the X-axis tick at data `index`, if any
+    fn x_label(&self, index: usize, granularity: Option<TimeGranularity>) -> Option<String> {
+        if !self.x_labels.is_empty() {
+            return self.x_labels.get(index).cloned();
+        }
+
+        let timestamp = granularity.and_then(|_| self.x_timestamps.get(index).copied());
+        match (&self.x_label_formatter, granularity, timestamp) {
+            (Some(formatter), Some(_), Some(t)) => Some(formatter.format(t as f64)),
+            (None, Some(granularity), Some(t)) => Some(granularity.format(t)),
+            (_, Some(_), None) => None,
+            (Some(formatter), None, _) => Some(formatter.format(index as f64)),
+            (None, None, _) => Some(index.to_string()),
+        }
+    }
+
+    /// Name used for the series at `index`
+    fn series_name(&self, index: usize) -> String {
+        self.series_names
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| format!("Series {}", index + 1))
+    }
+
+    /// Style used for the series at `index`
+    fn series_style(&self, index: usize) -> Style {
+        self.series_styles.get(index).copied().unwrap_or_default()
+    }
+
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<()> {
+        if self.height == 0 || self.width == 0 {
+            return Err(ChartError::InvalidDimensions);
+        }
+        if self.show_x_axis && self.x_label_interval == 0 {
+            return Err(ChartError::InvalidDimensions);
+        }
+        if self.bar_width == 0 {
+            return Err(ChartError::InvalidDimensions);
+        }
+        FormatSpec::parse(&self.label_format)?;
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min >= max {
+                return Err(ChartError::InvalidRange);
+            }
+        }
+        if let (Some(min), Some(max)) = (self.x_min, self.x_max) {
+            if min >= max {
+                return Err(ChartError::InvalidRange);
+            }
+        }
+        if let Scale::Log(base) = self.scale {
+            // Bases at or below 1 would be undefined or flip the axis
+            if !base.is_finite() || base <= 1.0 {
+                return Err(ChartError::InvalidRange);
+            }
+            if self.min.is_some_and(|m| m <= 0.0) || self.max.is_some_and(|m| m <= 0.0) {
+                return Err(ChartError::NonPositiveLogValue);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Main plotting function - creates an ASCII chart from a data series
+///
+/// # Arguments
+///
+/// * `series` - A slice of f64 values to plot
+/// * `config` - Configuration for chart rendering
+///
+/// # Returns
+///
+/// A String containing the rendered ASCII chart
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{plot_with_config, Config};
+///
+/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// let config = Config::new().with_height(15).with_width(60);
+/// let chart = plot_with_config(&data, config).unwrap();
+/// println!("{}", chart);
+/// ```
+pub fn plot_with_config(series: &[f64], config: Config) -> Result<String> {
+    config.validate()?;
+
+    if series.is_empty() {
+        return Err(ChartError::EmptyData);
+    }
+
+    if series.len() == 1 {
+        return Ok(config.format_label(series[0]));
+    }
+
+    plot_multiple_with_config(&[series], config)
+}
+
+/// Plot a series with explicitly missing samples
+///
+/// `None` samples are gaps, drawn according to `config.gap_policy` just
+/// like NaN values.
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{plot_optional_with_config, Config, GapPolicy};
+///
+/// let data = vec![Some(1.0), Some(3.0), None, None, Some(2.0), Some(4.0)];
+/// let config = Config::new().with_height(6).with_gap_policy(GapPolicy::Bridge);
+/// println!("{}", plot_optional_with_config(&data, config).unwrap());
+/// ```
+pub fn plot_optional_with_config(series: &[Option<f64>], config: Config) -> Result<String> {
+    let series: Vec<f64> = series.iter().map(|v| v.unwrap_or(f64::NAN)).collect();
+    plot_with_config(&series, config)
+}
+
+/// Plot several series onto the same canvas, sharing one Y-axis scale
+///
+/// The Y range is computed across every series (unless `config.min` /
+/// `config.max` are set). Series are drawn in order, so when two lines
+/// pass through the same cell the glyph of the later series wins; the
+/// last series in the slice is always on top.
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{plot_multiple_with_config, Config};
+///
+/// let up = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// let down = vec![5.0, 4.0, 3.0, 2.0, 1.0];
+/// let config = Config::new().with_height(8).with_width(40);
+/// let chart = plot_multiple_with_config(&[&up, &down], config).unwrap();
+/// println!("{}", chart);
+/// ```
+pub fn plot_multiple_with_config(series: &[&[f64]], config: Config) -> Result<String> {
+    config.validate()?;
+
+    if series.iter().all(|s| s.is_empty()) {
+        return Err(ChartError::EmptyData);
+    }
+
+    // A right-hand legend takes its columns out of the plot area
+    let mut plot_config = config.clone();
+    let mut legend_column = Vec::new();
+    if config.legend == Some(LegendPosition::Right) {
+        legend_column = legend_entries(series.len(), config.width / 3, &config);
+        // Keep the column beside the plot rows, summarising any entries that don't fit
+        let rows = config.height + 1;
+        if legend_column.len() > rows {
+            let more = format!("+{} more", legend_column.len() - (rows - 1));
+            legend_column.truncate(rows - 1);
+            legend_column.push((more.chars().count(), more));
+        }
+        let column_width = legend_column.iter().map(|(w, _)| *w).max().unwrap_or(0);
+        plot_config.width = config.width.saturating_sub(column_width + 1);
+        if plot_config.width < 2 {
+            return Err(ChartError::InvalidDimensions);
+        }
+    }
+
+    // So do reference line labels
+    let reference_width = config
+        .reference_lines
+        .iter()
+        .filter_map(|line| line.label.as_ref())
+        .map(|label| label.chars().count() + 1)
+        .max()
+        .unwrap_or(0);
+    plot_config.width = plot_config.width.saturating_sub(reference_width);
+    if plot_config.width < 2 {
+        return Err(ChartError::InvalidDimensions);
+    }
+
+    // Reduce long series to the points that fit, remembering where each came from;
+    // `positions` pairs plotted points with the data index they show
+    let points = series.iter().map(|s| s.len()).max().unwrap_or(0);
+    let capacity = plot_config.width.saturating_sub(1) * plot_config.render_mode.samples_per_column();
+    let (reduced, mut positions) = match plot_config.downsample {
+        Some(downsample) if points > capacity => {
+            let reduced: Vec<Vec<(usize, f64)>> =
+                series.iter().map(|s| downsample.apply(s, points, capacity)).collect();
+            let longest = reduced.iter().max_by_key(|r| r.len()).cloned().unwrap_or_default();
+            let values: Vec<Vec<f64>> = reduced.iter().map(|r| r.iter().map(|p| p.1).collect()).collect();
+            (Some(values), longest.iter().map(|p| p.0).enumerate().collect())
+        }
+        _ => (None, (0..points.min(capacity)).map(|i| (i, i)).collect::<Vec<(usize, usize)>>()),
+    };
+    let series: Vec<&[f64]> = match &reduced {
+        Some(reduced) => reduced.iter().map(|s| s.as_slice()).collect(),
+        None => series.to_vec(),
+    };
+
+    // On a log scale, plot the logarithms and label with the original values
+    let scaled = apply_scale(&series, &config)?;
+    let series: Vec<&[f64]> = match &scaled {
+        Some(scaled) => scaled.iter().map(|s| s.as_slice()).collect(),
+        None => series,
+    };
+
+    // Spread short series across the plot, in scale space so segments stay straight
+    let stretched = match plot_config.stretch {
+        Some(interpolation) if points >= 2 && points < capacity => {
+            positions = (0..points).map(|i| (stretched_column(i, points, capacity), i)).collect();
+            let stretched: Vec<Vec<f64>> = series
+                .iter()
+                .map(|s| stretch(s, points, capacity, interpolation, plot_config.step))
+                .collect();
+            Some(stretched)
+        }
+        _ => None,
+    };
+    let series: Vec<&[f64]> = match &stretched {
+        Some(stretched) => stretched.iter().map(|s| s.as_slice()).collect(),
+        None => series,
+    };
+    let series = series.as_slice();
+
+    let (min, max) = series_bounds(series, &config)?;
+
+    // Handle case where all values are the same
+    if (max - min).abs() < f64::EPSILON {
+        return Ok(config.format_axis_value(min));
+    }
+
+    // Initialize canvas - no extra width needed
+    let mut canvas: Vec<Vec<Cell>> = vec![vec![Cell::BLANK; plot_config.width]; plot_config.height + 1];
+    let reference_labels = draw_references(&mut canvas, min, max, &plot_config);
+
+    match plot_config.render_mode {
+        RenderMode::Lines => {
+            if plot_config.area.is_some() {
+                for (index, s) in series.iter().enumerate() {
+                    draw_area(&mut canvas, s, index, min, max, &plot_config);
+                }
+            }
+            for (index, s) in series.iter().enumerate() {
+                match plot_config.step {
+                    Some(mode) => draw_steps(&mut canvas, s, index, min, max, mode, &plot_config),
+                    None => draw_series(&mut canvas, s, index, min, max, &plot_config),
+                }
+            }
+        }
+        RenderMode::Braille => {
+            for (index, s) in series.iter().enumerate() {
+                draw_braille(&mut canvas, s, index, min, max, &plot_config);
+            }
+        }
+        RenderMode::HalfBlock | RenderMode::HalfBlockFilled => {
+            for (index, s) in series.iter().enumerate() {
+                draw_half_blocks(&mut canvas, s, index, min, max, &plot_config);
+            }
+        }
+    }
+
+    if plot_config.gap_policy == GapPolicy::Mark {
+        for (index, s) in series.iter().enumerate() {
+            mark_gaps(&mut canvas, s, index, &plot_config);
+        }
+    }
+    let (above, below) = mark_clipped(&mut canvas, series, &positions, min, max, &plot_config);
+
+    let mut lines = render_canvas(&canvas, min, max, &plot_config);
+    let gutter = if plot_config.show_labels {
+        label_width(min, max, &plot_config)
+    } else {
+        0
+    };
+    let chart_width = gutter + plot_config.width + reference_width;
+
+    if reference_width > 0 {
+        for (line, label) in lines.iter_mut().zip(reference_labels) {
+            let (text, style) = match label.map(|i| &plot_config.reference_lines[i]) {
+                Some(ReferenceLine { label: Some(text), style, .. }) => (text.as_str(), *style),
+                _ => ("", Style::default()),
+            };
+            let padding = reference_width - 1 - text.chars().count();
+            line.push(' ');
+            line.push_str(&style.paint(text));
+            line.push_str(&" ".repeat(padding));
+        }
+    }
+
+    if plot_config.show_x_axis {
+        lines.append(&mut render_x_axis(&positions, gutter, &plot_config));
+    }
+
+    match config.legend {
+        Some(LegendPosition::Top) => {
+            let mut legend = legend_rows(series.len(), chart_width, &config);
+            legend.append(&mut lines);
+            lines = legend;
+        }
+        Some(LegendPosition::Bottom) => {
+            lines.append(&mut legend_rows(series.len(), chart_width, &config));
+        }
+        Some(LegendPosition::Right) => {
+            for (line, (_, entry)) in lines.iter_mut().zip(legend_column) {
+                line.push(' ');
+                line.push_str(&entry);
+            }
+        }
+        None => {}
+    }
+
+    if config.clip_report && above + below > 0 {
+        let noun = if above + below == 1 { "point" } else { "points" };
+        let mut counts = Vec::new();
+        if above > 0 {
+            counts.push(format!("{} {} above", config.symbols.overflow, above));
+        }
+        if below > 0 {
+            counts.push(format!("{} {} below", config.symbols.underflow, below));
+        }
+        lines.push(format!("{} {} outside range ({})", above + below, noun, counts.join(", ")));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// Build one legend entry per series as (visible width, rendered text)
+///
+/// Each entry is a two-glyph sample of the series line in its style followed
+/// by the series name, truncated so the entry is at most `max_width` wide.
+fn legend_entries(count: usize, max_width: usize, config: &Config) -> Vec<(usize, String)> {
+    (0..count)
+        .map(|index| {
+            let sample = match config.render_mode {
+                RenderMode::Lines => config.symbols.horizontal.to_string().repeat(2),
+                RenderMode::Braille => "⠒⠒".to_string(),
+                RenderMode::HalfBlock => "▄▄".to_string(),
+                RenderMode::HalfBlockFilled => "██".to_string(),
+            };
+            let mut name = config.series_name(index);
+            let room = max_width.saturating_sub(3);
+            if name.chars().count() > room {
+                let keep = room.saturating_sub(2);
+                name = name.chars().take(keep).chain("..".chars()).take(room).collect();
+            }
+            let width = 3 + name.chars().count();
+            let text = format!("{} {}", config.series_style(index).paint(&sample), name);
+            (width, text)
+        })
+        .collect()
+}
+
+/// Lay legend entries out left to right, wrapping so no row exceeds `width`
+fn legend_rows(count: usize, width: usize, config: &Config) -> Vec<String> {
+    let mut rows = Vec::new();
+    let mut row = String::new();
+    let mut row_width = 0;
+
+    for (entry_width, entry) in legend_entries(count, width, config) {
+        if row_width > 0 && row_width + 2 + entry_width > width {
+            rows.push(std::mem::take(&mut row));
+            row_width = 0;
+        }
+        if row_width > 0 {
+            row.push_str("  ");
+            row_width += 2;
+        }
+        row.push_str(&entry);
+        row_width += entry_width;
+    }
+    if row_width > 0 {
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Transform series into scale space, or None when the scale is linear
+///
+/// Non-positive values on a log scale become NaN gaps if `log_gaps` is set
+/// and are an error otherwise.
+fn apply_scale(series: &[&[f64]], config: &Config) -> Result<Option<Vec<Vec<f64>>>> {
+    if config.scale == Scale::Linear {
+        return Ok(None);
+    }
+
+    series
+        .iter()
+        .map(|s| {
+            s.iter()
+                .map(|&v| {
+                    if !v.is_finite() || v > 0.0 {
+                        Ok(config.scale.apply(v))
+                    } else if config.log_gaps {
+                        Ok(f64::NAN)
+                    } else {
+                        Err(ChartError::NonPositiveLogValue)
+                    }
+                })
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<f64>>>>()
+        .map(Some)
+}
+
+impl Downsample {
+    /// Reduce `series` to at most `capacity` points as (data index, value)
+    ///
+    /// Buckets are laid out for a series of `len` samples; a shorter series
+    /// simply runs out of buckets early. Buckets without a finite sample
+    /// become NaN gaps.
+    fn apply(self, series: &[f64], len: usize, capacity: usize) -> Vec<(usize, f64)> {
+        let capacity = capacity.max(1);
+        let bucket = |b: usize, buckets: usize| {
+            (b * len / buckets).min(series.len())..((b + 1) * len / buckets).min(series.len())
+        };
+
+        match self {
+            Downsample::EveryNth => {
+                let stride = len.div_ceil(capacity);
+                series.iter().copied().enumerate().step_by(stride).collect()
+            }
+            Downsample::Mean => (0..capacity)
+                .map(|b| bucket(b, capacity))
+                .take_while(|range| !range.is_empty())
+                .map(|range| {
+                    let finite: Vec<f64> =
+                        series[range.clone()].iter().copied().filter(|v| v.is_finite()).collect();
+                    let mean = if finite.is_empty() {
+                        f64::NAN
+                    } else {
+                        finite.iter().sum::<f64>() / finite.len() as f64
+                    };
+                    (range.start, mean)
+                })
+                .collect(),
+            Downsample::MinMax => {
+                let buckets = (capacity / 2).max(1);
+                (0..buckets)
+                    .map(|b| bucket(b, buckets))
+                    .take_while(|range| !range.is_empty())
+                    .flat_map(|range| {
+                        let finite = || range.clone().filter(|&i| series[i].is_finite());
+                        let low = finite().min_by(|&a, &b| series[a].total_cmp(&series[b]));
+                        let high = finite().max_by(|&a, &b| series[a].total_cmp(&series[b]));
+                        match (low, high) {
+                            (Some(low), Some(high)) => {
+                                let (first, second) = (low.min(high), low.max(high));
+                                [(first, series[first]), (second, series[second])]
+                            }
+                            _ => [(range.start, f64::NAN); 2],
+                        }
+                    })
+                    .collect()
+            }
+            Downsample::Lttb if capacity < 3 => Downsample::EveryNth.apply(series, len, capacity),
+            Downsample::Lttb => lttb(series, len, capacity),
+        }
+    }
+}
+
+/// Largest-Triangle-Three-Buckets downsampling to `threshold` points
+///
+/// The first and last samples are always kept. Every bucket in between
+/// keeps the sample forming the largest triangle with the previously kept
+/// sample and the average of the next bucket.
+fn lttb(series: &[f64], len: usize, threshold: usize) -> Vec<(usize, f64)> {
+    if series.is_empty() {
+        return Vec::new();
+    }
+
+    let size = (len - 2) as f64 / (threshold - 2) as f64;
+    let range = |b: usize| {
+        let start = ((b as f64 * size) as usize + 1).min(series.len());
+        let end = (((b + 1) as f64 * size) as usize + 1).min(len - 1).min(series.len());
+        start..end.max(start)
+    };
+
+    let mut kept = vec![(0, series[0])];
+    let mut anchor = (0.0, series[0]);
+
+    for b in 0..threshold - 2 {
+        let current = range(b);
+        if current.is_empty() {
+            break;
+        }
+
+        // Average of the next bucket (the last sample after the final bucket)
+        let next = if b + 3 < threshold {
+            range(b + 1)
+        } else {
+            (len - 1).min(series.len())..series.len()
+        };
+        let finite: Vec<usize> = next.filter(|&i| series[i].is_finite()).collect();
+        let average = match finite.len() {
+            0 => anchor,
+            n => (
+                finite.iter().sum::<usize>() as f64 / n as f64,
+                finite.iter().map(|&i| series[i]).sum::<f64>() / n as f64,
+            ),
+        };
+
+        let mut best: Option<(usize, f64)> = None;
+        for i in current.clone().filter(|&i| series[i].is_finite()) {
+            let area = ((anchor.0 - average.0) * (series[i] - anchor.1)
+                - (anchor.0 - i as f64) * (average.1 - anchor.1))
+                .abs();
+            if best.is_none_or(|(_, largest)| area > largest) {
+                best = Some((i, area));
+            }
+        }
+
+        match best {
+            Some((i, _)) => {
+                kept.push((i, series[i]));
+                anchor = (i as f64, series[i]);
+            }
+            None => kept.push((current.start, f64::NAN)),
+        }
+    }
+
+    if series.len() == len {
+        kept.push((len - 1, series[len - 1]));
+    }
+    kept
+}
+
+/// Resample `series` onto `capacity` evenly spaced points
+///
+/// Sample `i` lands exactly on point [`stretched_column`]`(i, len, capacity)`,
+/// where `len` is the length of the longest series, so series of different
+/// lengths stay aligned. Points between samples are interpolated; in step
+/// charts they hold the value the step mode gives them. Intervals next to
+/// a non-finite sample stay non-finite.
+fn stretch(
+    series: &[f64],
+    len: usize,
+    capacity: usize,
+    interpolation: Interpolation,
+    step: Option<StepMode>,
+) -> Vec<f64> {
+    let tangents = match interpolation {
+        Interpolation::MonotoneCubic => monotone_tangents(series),
+        _ => Vec::new(),
+    };
+    let mut points = Vec::with_capacity(capacity);
+
+    for (i, pair) in series.windows(2).enumerate() {
+        let (y0, y1) = (pair[0], pair[1]);
+        let (x0, x1) = (stretched_column(i, len, capacity), stretched_column(i + 1, len, capacity));
+
+        for x in x0..x1 {
+            let f = (x - x0) as f64 / (x1 - x0) as f64;
+            let value = match (step, interpolation) {
+                _ if x == x0 => y0,
+                (Some(StepMode::Before), _) => y1,
+                (Some(StepMode::After), _) => y0,
+                (Some(StepMode::Mid), _) | (None, Interpolation::Nearest) => if f < 0.5 { y0 } else { y1 },
+                (None, Interpolation::Linear) => y0 + (y1 - y0) * f,
+                (None, Interpolation::MonotoneCubic) => {
+                    let (m0, m1) = (tangents[i], tangents[i + 1]);
+                    let (f2, f3) = (f * f, f * f * f);
+                    (2.0 * f3 - 3.0 * f2 + 1.0) * y0
+                        + (f3 - 2.0 * f2 + f) * m0
+                        + (-2.0 * f3 + 3.0 * f2) * y1
+                        + (f3 - f2) * m1
                 }
-            } else {
-                " ".repeat(label_width)
             };
+            points.push(value);
+        }
+    }
+    points.extend(series.last());
+
+    points
+}
+
+/// Point that sample `i` of a `len`-sample series is stretched onto
+fn stretched_column(i: usize, len: usize, capacity: usize) -> usize {
+    (i * (capacity - 1) + (len - 1) / 2) / (len - 1)
+}
+
+/// Tangent at each sample for monotone cubic interpolation (Fritsch–Butland)
+///
+/// Interior tangents are the harmonic mean of the neighbouring slopes, or
+/// zero at a local extremum, so the curve never overshoots the samples.
+fn monotone_tangents(series: &[f64]) -> Vec<f64> {
+    let slopes: Vec<f64> = series.windows(2).map(|w| w[1] - w[0]).collect();
+
+    (0..series.len())
+        .map(|i| match (i.checked_sub(1).map(|k| slopes[k]), slopes.get(i).copied()) {
+            (Some(before), Some(after)) if before * after > 0.0 => {
+                2.0 * before * after / (before + after)
+            }
+            (Some(before), Some(after)) if before.is_finite() && after.is_finite() => 0.0,
+            // Next to a gap, follow the slope on the side with data
+            (Some(before), Some(after)) => if before.is_finite() { before } else { after },
+            (Some(slope), None) | (None, Some(slope)) => slope,
+            (None, None) => 0.0,
+        })
+        .collect()
+}
+
+/// Determine the Y-axis range shared by all series, in scale space
+fn series_bounds(series: &[&[f64]], config: &Config) -> Result<(f64, f64)> {
+    let (data_min, data_max) = finite_bounds(series).ok_or(ChartError::InvalidRange)?;
+
+    // Determine min and max
+    let fixed_min = config.min.map(|m| config.scale.apply(m));
+    let fixed_max = config.max.map(|m| config.scale.apply(m));
+
+    let min = fixed_min.unwrap_or(data_min);
+    let max = fixed_max.unwrap_or(data_max);
+
+    if !min.is_finite() || !max.is_finite() {
+        return Err(ChartError::InvalidRange);
+    }
+
+    // Widen auto-calculated bounds out to nice tick values (whole powers on a log scale)
+    if config.tick_mode == TickMode::Nice && config.nice_bounds && max > min {
+        let step = match config.scale {
+            Scale::Linear => nice_step(max - min, config.label_ticks),
+            Scale::Log(_) => 1.0,
+        };
+        if !step.is_finite() {
+            return Ok((min, max));
+        }
+        let min = match fixed_min {
+            Some(fixed) => fixed,
+            None => (min / step + TICK_EPSILON).floor() * step,
+        };
+        let max = match fixed_max {
+            Some(fixed) => fixed,
+            None => (max / step - TICK_EPSILON).ceil() * step,
+        };
+        return Ok((min, max));
+    }
+
+    Ok((min, max))
+}
+
+/// Min and max of the finite values across all series, if there are any
+///
+/// NaN and infinite values are ignored so a single bad sample cannot blow
+/// up the scale.
+fn finite_bounds(series: &[&[f64]]) -> Option<(f64, f64)> {
+    series
+        .iter()
+        .flat_map(|s| s.iter().copied())
+        .filter(|v| v.is_finite())
+        .fold(None, |bounds, v| match bounds {
+            None => Some((v, v)),
+            Some((min, max)) => Some((f64::min(min, v), f64::max(max, v))),
+        })
+}
+
+/// Tolerance used when snapping values to multiples of a tick step
+const TICK_EPSILON: f64 = 1e-9;
+
+/// Pick a 1-2-5 × 10^n step that splits `range` into about `intervals` parts
+fn nice_step(range: f64, intervals: usize) -> f64 {
+    let rough = range / intervals.max(1) as f64;
+    let magnitude = 10f64.powf(rough.log10().floor());
+    let residual = rough / magnitude;
+
+    let nice = if residual <= 1.0 {
+        1.0
+    } else if residual <= 2.0 {
+        2.0
+    } else if residual <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    nice * magnitude
+}
+
+/// Multiples of the nice step that fall within `[min, max]`; None if the
+/// range is inverted or too wide for a finite step
+fn nice_ticks(min: f64, max: f64, intervals: usize) -> Option<Vec<f64>> {
+    let step = nice_step(max - min, intervals);
+    if !step.is_finite() || step <= 0.0 {
+        return None;
+    }
+    let first = (min / step - TICK_EPSILON).ceil() as i64;
+    let last = (max / step + TICK_EPSILON).floor() as i64;
+
+    // Multiply rather than accumulate so values stay as exact as possible
+    Some((first..=last).map(|k| k as f64 * step).collect())
+}
+
+/// What drew a canvas cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CellKind {
+    /// A series line or marker
+    Line,
+    /// A series area fill
+    Fill,
+    /// A reference line; the cell's index is into `config.reference_lines`
+    Reference,
+}
+
+/// A single canvas cell: the glyph, the index of the series (or reference
+/// line) that drew it, and what kind of mark it is
+#[derive(Debug, Clone, Copy)]
+struct Cell {
+    ch: char,
+    series: Option<usize>,
+    kind: CellKind,
+}
+
+impl Cell {
+    const BLANK: Cell = Cell { ch: ' ', series: None, kind: CellKind::Line };
+
+    fn new(ch: char, series: usize) -> Self {
+        Self { ch, series: Some(series), kind: CellKind::Line }
+    }
+
+    fn fill(ch: char, series: usize) -> Self {
+        Self { ch, series: Some(series), kind: CellKind::Fill }
+    }
+
+    fn reference(ch: char, index: usize) -> Self {
+        Self { ch, series: Some(index), kind: CellKind::Reference }
+    }
+
+    /// Whether the cell is empty or only holds a reference line
+    fn is_background(&self) -> bool {
+        self.series.is_none() || self.kind == CellKind::Reference
+    }
+}
+
+/// Draw each reference line across the plot on the row its value maps to
+///
+/// Lines outside `[min, max]` are not drawn. Returns the label for each
+/// row; when two labelled lines share a row, the first one keeps it.
+fn draw_references(canvas: &mut [Vec<Cell>], min: f64, max: f64, config: &Config) -> Vec<Option<usize>> {
+    let height = config.height;
+    let ratio = (height as f64) / (max - min);
+    let mut labels = vec![None; height + 1];
+
+    for (index, line) in config.reference_lines.iter().enumerate() {
+        let value = config.scale.apply(line.value);
+        if !(min..=max).contains(&value) {
+            continue;
+        }
+
+        let row = (((max - value) * ratio).round() as usize).min(height);
+        for cell in canvas[row].iter_mut().skip(1) {
+            *cell = Cell::reference(config.symbols.reference, index);
+        }
+        if line.label.is_some() && labels[row].is_none() {
+            labels[row] = Some(index);
+        }
+    }
+
+    labels
+}
+
+/// Fill the cells between each sample and the area baseline
+///
+/// Fills are drawn before any line, so every line stays on top; where the
+/// fills of two series overlap, the later series wins. Reference lines
+/// stay visible through the fill.
+fn draw_area(canvas: &mut [Vec<Cell>], series: &[f64], index: usize, min: f64, max: f64, config: &Config) {
+    let height = config.height;
+    let ratio = (height as f64) / (max - min);
+    let baseline = match config.area {
+        Some(AreaBaseline::Zero) => {
+            // A fixed bound on the wrong side of the data can invert the range
+            let zero = config.scale.apply(0.0).clamp(min.min(max), max.max(min));
+            (((max - zero) * ratio).round().max(0.0) as usize).min(height)
+        }
+        _ => height,
+    };
+
+    let visible = &series[..series.len().min(config.width.saturating_sub(1))];
+    // A step-before line rises in the column of the earlier sample, so that
+    // column is filled from the value it steps to
+    let next = |x: usize| match config.gap_policy {
+        GapPolicy::Bridge => visible[x + 1..].iter().copied().find(|v| v.is_finite()),
+        _ => visible.get(x + 1).copied().filter(|v| v.is_finite()),
+    };
+
+    for (x, &value) in visible.iter().enumerate() {
+        if !value.is_finite() {
+            continue;
+        }
+        let value = match config.step {
+            Some(StepMode::Before) => next(x).unwrap_or(value),
+            _ => value,
+        };
+
+        let y = (((max - value) * ratio).round().max(0.0) as usize).min(height);
+        let rows = if y < baseline { y + 1..baseline + 1 } else { baseline..y };
+        for row in &mut canvas[rows] {
+            if row[x + 1].kind != CellKind::Reference {
+                row[x + 1] = Cell::fill(config.symbols.area_fill, index);
+            }
+        }
+    }
+}
+
+/// Draw one series onto the canvas as a connected line
+fn draw_series(canvas: &mut [Vec<Cell>], series: &[f64], index: usize, min: f64, max: f64, config: &Config) {
+    let height = config.height;
+    let ratio = (height as f64) / (max - min);
+
+    // Plot the line - SKIP x=0 (reserved for axis separator)
+    let mut y0: Option<(usize, usize)> = None;
+    let mut started = false;
+
+    for (x, &value) in series.iter().enumerate().take(config.width.saturating_sub(1)) {
+        if !value.is_finite() {
+            if config.gap_policy != GapPolicy::Bridge {
+                y0 = None;
+            }
+            continue;
+        }
+
+        let y = ((max - value) * ratio).round() as usize;
+        let y = y.min(height);
+
+        let plot_x = x + 1; // Start from x=1, skip x=0
+
+        if let Some((x_prev, mut y_prev)) = y0 {
+            // Bridge a gap with dotted glyphs on the straight line between the samples
+            let bridge: Vec<(usize, usize)> = (x_prev + 1..plot_x)
+                .map(|column| {
+                    let t = (column - x_prev) as f64 / (plot_x - x_prev) as f64;
+                    (column, (y_prev as f64 + (y as f64 - y_prev as f64) * t).round() as usize)
+                })
+                .collect();
+            for &(column, row) in &bridge {
+                canvas[row][column] = Cell::new(config.symbols.gap_bridge, index);
+            }
+            if let Some(&(_, row)) = bridge.last() {
+                y_prev = row;
+            }
+            draw_riser(canvas, plot_x, y_prev, y, index, config);
+        } else if started {
+            // First point after a gap
+            canvas[y][plot_x] = Cell::new(config.symbols.horizontal, index);
+        } else {
+            draw_first_point(canvas, plot_x, y, index, config);
+        }
+
+        y0 = Some((plot_x, y));
+        started = true;
+    }
+}
+
+/// Mark samples outside `[min, max]` at the top or bottom of their column
+///
+/// `positions` gives the plotted point of each sample, as for the X axis.
+/// Points above the range get `symbols.overflow` in the top row and points
+/// below it `symbols.underflow` in the bottom row, replacing whatever the
+/// line drew there. Returns how many points were above and below.
+fn mark_clipped(
+    canvas: &mut [Vec<Cell>],
+    series: &[&[f64]],
+    positions: &[(usize, usize)],
+    min: f64,
+    max: f64,
+    config: &Config,
+) -> (usize, usize) {
+    let per_column = config.render_mode.samples_per_column();
+    let visible = config.width.saturating_sub(1) * per_column;
+    let (mut above, mut below) = (0, 0);
+
+    for (index, s) in series.iter().enumerate() {
+        for &(x, _) in positions.iter().take_while(|&&(x, _)| x < visible) {
+            let Some(&value) = s.get(x) else {
+                break;
+            };
+            let (row, marker) = if value > max {
+                above += 1;
+                (0, config.symbols.overflow)
+            } else if value < min {
+                below += 1;
+                (config.height, config.symbols.underflow)
+            } else {
+                continue;
+            };
+            canvas[row][x / per_column + 1] = Cell::new(marker, index);
+        }
+    }
+
+    (above, below)
+}
+
+/// Put `symbols.gap_mark` in the bottom row of each column with a missing value
+///
+/// Marks never replace a series glyph already drawn there.
+fn mark_gaps(canvas: &mut [Vec<Cell>], series: &[f64], index: usize, config: &Config) {
+    let per_column = config.render_mode.samples_per_column();
+    let columns = config.width.saturating_sub(1);
+    let bottom = config.height;
+
+    for (x, value) in series.iter().enumerate().take(columns * per_column) {
+        let cell = &mut canvas[bottom][x / per_column + 1];
+        if !value.is_finite() && cell.is_background() {
+            *cell = Cell::new(config.symbols.gap_mark, index);
+        }
+    }
+}
+
+/// Draw the column where a line enters at row `from` and leaves at row `to`
+///
+/// Equal rows give a horizontal glyph; otherwise corners join a vertical run.
+fn draw_riser(canvas: &mut [Vec<Cell>], x: usize, from: usize, to: usize, index: usize, config: &Config) {
+    if from == to {
+        // Horizontal line
+        canvas[to][x] = Cell::new(config.symbols.horizontal, index);
+        return;
+    }
+
+    // Vertical movement
+    let (y_start, y_end) = if from < to { (from, to) } else { (to, from) };
+
+    // Draw vertical connection
+    for (y_line, row) in canvas.iter_mut().enumerate().take(y_end + 1).skip(y_start) {
+        let ch = if y_line == from {
+            if from < to {
+                config.symbols.top_right
+            } else {
+                config.symbols.bottom_right
+            }
+        } else if y_line == to {
+            if from < to {
+                config.symbols.bottom_left
+            } else {
+                config.symbols.top_left
+            }
+        } else {
+            config.symbols.vertical
+        };
+        row[x] = Cell::new(ch, index);
+    }
+}
+
+/// Draw the glyph that starts a series
+///
+/// With labels shown, the Y axis stands in for a series starting in the
+/// first column, so nothing is drawn there and the cell keeps whatever is
+/// behind it (a candle wick or step riser, say). Anywhere else the start is
+/// a plain horizontal stub, as after a gap.
+fn draw_first_point(canvas: &mut [Vec<Cell>], x: usize, y: usize, index: usize, config: &Config) {
+    if !(config.show_labels && x == 1) {
+        canvas[y][x] = Cell::new(config.symbols.horizontal, index);
+    }
+}
+
+/// Draw one series onto the canvas as a step line
+///
+/// Each value is held as a horizontal run, and the change to the next value
+/// is drawn as a vertical riser in the column chosen by `mode`.
+fn draw_steps(
+    canvas: &mut [Vec<Cell>],
+    series: &[f64],
+    index: usize,
+    min: f64,
+    max: f64,
+    mode: StepMode,
+    config: &Config,
+) {
+    let height = config.height;
+    let ratio = (height as f64) / (max - min);
+    let mut previous: Option<(usize, usize)> = None;
+    let mut started = false;
+
+    for (x, &value) in series.iter().enumerate().take(config.width.saturating_sub(1)) {
+        if !value.is_finite() {
+            if config.gap_policy != GapPolicy::Bridge {
+                previous = None;
+            }
+            continue;
+        }
+
+        let y = (((max - value) * ratio).round() as usize).min(height);
+        let plot_x = x + 1;
+
+        match previous {
+            Some((x0, y0)) => {
+                let riser = match mode {
+                    StepMode::Before => x0,
+                    StepMode::After => plot_x,
+                    StepMode::Mid => x0 + (plot_x - x0).div_ceil(2),
+                };
+                // Runs only cross skipped columns when bridging a gap
+                let run = Cell::new(config.symbols.gap_bridge, index);
+                canvas[y0].iter_mut().take(riser).skip(x0 + 1).for_each(|cell| *cell = run);
+                draw_riser(canvas, riser, y0, y, index, config);
+                canvas[y].iter_mut().take(plot_x).skip(riser + 1).for_each(|cell| *cell = run);
+                if riser < plot_x {
+                    canvas[y][plot_x] = Cell::new(config.symbols.horizontal, index);
+                }
+            }
+            None if started => canvas[y][plot_x] = Cell::new(config.symbols.horizontal, index),
+            None => draw_first_point(canvas, plot_x, y, index, config),
+        }
+
+        previous = Some((plot_x, y));
+        started = true;
+    }
+}
+
+/// Whole powers of the base within `[min, max]` (given as exponents), thinned
+/// to about `intervals` steps; None if fewer than two are visible
+fn decade_ticks(min: f64, max: f64, intervals: usize) -> Option<Vec<f64>> {
+    let first = (min - TICK_EPSILON).ceil() as i64;
+    let last = (max + TICK_EPSILON).floor() as i64;
+    if last <= first {
+        return None;
+    }
+
+    let step = ((last - first) as usize).div_ceil(intervals.max(1)).max(1);
+    Some((first..=last).step_by(step).map(|k| k as f64).collect())
+}
+
+/// Label the rows closest to each tick value (given in scale space)
+fn place_ticks(ticks: &[f64], min: f64, max: f64, config: &Config) -> Vec<Option<String>> {
+    let height = config.height;
+    let mut labels = vec![None; height + 1];
+    for &value in ticks {
+        let row = ((max - value) * height as f64 / (max - min)).round() as usize;
+        // When two ticks snap to the same row, the first one keeps it
+        if let Some(slot @ None) = labels.get_mut(row) {
+            *slot = Some(config.format_axis_value(value));
+        }
+    }
+    labels
+}
+
+/// Y-axis label text for each canvas row (None for unlabelled rows)
+fn y_labels(min: f64, max: f64, config: &Config) -> Vec<Option<String>> {
+    let range = max - min;
+    let height = config.height;
+
+    if let Scale::Log(_) = config.scale {
+        if let Some(ticks) = decade_ticks(min, max, config.label_ticks) {
+            return place_ticks(&ticks, min, max, config);
+        }
+    }
+
+    // Without a usable nice step, fall back to labelling every few rows
+    if config.tick_mode == TickMode::Nice {
+        if let Some(ticks) = nice_ticks(min, max, config.label_ticks) {
+            return place_ticks(&ticks, min, max, config);
+        }
+    }
+
+    (0..=height)
+        .map(|idx| {
+            let y_value = max - (idx as f64 * range / height as f64);
+
+            // Determine if this row should have a label
+            if idx == 0 {
+                Some(config.format_axis_value(max))
+            } else if idx == height {
+                Some(config.format_axis_value(min))
+            } else if config.label_ticks > 0 && height >= config.label_ticks {
+                let step = height / config.label_ticks;
+                if step > 0 && idx % step == 0 {
+                    Some(config.format_axis_value(y_value))
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Width of the Y-axis label gutter, wide enough for every label drawn
+fn label_width(min: f64, max: f64, config: &Config) -> usize {
+    y_labels(min, max, config)
+        .iter()
+        .flatten()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Braille dot bits, indexed by [dot column][dot row] within a cell
+const BRAILLE_DOTS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// First codepoint of the Unicode braille patterns block
+const BRAILLE_BASE: u32 = 0x2800;
+
+/// Draw one series onto the canvas as braille dots
+///
+/// Each cell holds 2×4 dots, so sample `i` lands in dot column `i` (canvas
+/// column `i / 2 + 1`) and values resolve to a quarter of a row. Dot rows
+/// are centred on the rows the Y-axis labels refer to. Consecutive samples
+/// are joined with straight dot lines; non-finite values are handled by
+/// `config.gap_policy`.
+fn draw_braille(canvas: &mut [Vec<Cell>], series: &[f64], index: usize, min: f64, max: f64, config: &Config) {
+    let dot_columns = config.width.saturating_sub(1) * 2;
+    let last_dot_row = (config.height + 1) * 4 - 1;
+    let mut previous: Option<(usize, usize)> = None;
+
+    for (x, &value) in series.iter().enumerate().take(dot_columns) {
+        if !value.is_finite() {
+            if config.gap_policy != GapPolicy::Bridge {
+                previous = None;
+            }
+            continue;
+        }
+
+        let row = (max - value) / (max - min) * config.height as f64;
+        let y = ((row * 4.0 + 1.5).round().max(0.0) as usize).min(last_dot_row);
+
+        match previous {
+            Some((x0, y0)) => {
+                // Bridged gaps are drawn dotted
+                let step = if x > x0 + 1 { 2 } else { 1 };
+                for (dx, dy) in line_points(x0, y0, x, y).into_iter().step_by(step) {
+                    set_braille_dot(canvas, dx, dy, index);
+                }
+                set_braille_dot(canvas, x, y, index);
+            }
+            None => set_braille_dot(canvas, x, y, index),
+        }
+
+        previous = Some((x, y));
+    }
+}
+
+/// Turn on one braille dot, merging with dots already in the cell
+fn set_braille_dot(canvas: &mut [Vec<Cell>], x: usize, y: usize, series: usize) {
+    let cell = &mut canvas[y / 4][x / 2 + 1];
+    let existing = match cell.ch as u32 {
+        c @ BRAILLE_BASE..=0x28FF => c - BRAILLE_BASE,
+        _ => 0,
+    };
+    let bits = existing | BRAILLE_DOTS[x % 2][y % 4];
+    *cell = Cell::new(char::from_u32(BRAILLE_BASE + bits).unwrap_or(' '), series);
+}
+
+/// Points on the straight line between two grid points (Bresenham)
+fn line_points(x0: usize, y0: usize, x1: usize, y1: usize) -> Vec<(usize, usize)> {
+    let (mut x, mut y) = (x0 as i64, y0 as i64);
+    let (x1, y1) = (x1 as i64, y1 as i64);
+    let dx = (x1 - x).abs();
+    let dy = -(y1 - y).abs();
+    let sx = if x < x1 { 1 } else { -1 };
+    let sy = if y < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+    let mut points = Vec::new();
+
+    loop {
+        points.push((x as usize, y as usize));
+        if x == x1 && y == y1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+
+    points
+}
+
+/// Draw one series onto the canvas with half-block glyphs
+///
+/// Every row is split into an upper and lower half. Values map onto the
+/// `2 * (height + 1)` half rows so that a labelled value always falls in
+/// its labelled row. In line mode each column fills the half rows between
+/// the previous and the current sample; in filled mode it fills everything
+/// from the sample down to the bottom of the chart.
+fn draw_half_blocks(canvas: &mut [Vec<Cell>], series: &[f64], index: usize, min: f64, max: f64, config: &Config) {
+    let last_half = config.height * 2 + 1;
+    let filled = config.render_mode == RenderMode::HalfBlockFilled;
+    let mut previous: Option<usize> = None;
+
+    for (x, &value) in series.iter().enumerate().take(config.width.saturating_sub(1)) {
+        if !value.is_finite() {
+            previous = None;
+            continue;
+        }
+
+        let half = (max - value) / (max - min) * last_half as f64;
+        let half = (half.round().max(0.0) as usize).min(last_half);
+
+        let (from, to) = if filled {
+            (half, last_half)
+        } else {
+            match previous {
+                // Step one half row past the previous sample so runs don't overlap
+                Some(prev) if prev < half => (prev + 1, half),
+                Some(prev) if prev > half => (half, prev - 1),
+                _ => (half, half),
+            }
+        };
+
+        for h in from..=to {
+            set_half_block(canvas, x + 1, h, index);
+        }
+
+        previous = Some(half);
+    }
+}
+
+/// Turn on the upper or lower half of a cell, merging with what is already there
+fn set_half_block(canvas: &mut [Vec<Cell>], x: usize, half: usize, series: usize) {
+    let cell = &mut canvas[half / 2][x];
+    let (mut upper, mut lower) = match cell.ch {
+        '▀' => (true, false),
+        '▄' => (false, true),
+        '█' => (true, true),
+        _ => (false, false),
+    };
+    if half.is_multiple_of(2) {
+        upper = true;
+    } else {
+        lower = true;
+    }
+    let ch = match (upper, lower) {
+        (true, true) => '█',
+        (true, false) => '▀',
+        _ => '▄',
+    };
+    *cell = Cell::new(ch, series);
+}
+
+/// Turn a drawn canvas into output rows, adding Y-axis labels if enabled
+fn render_canvas(canvas: &[Vec<Cell>], min: f64, max: f64, config: &Config) -> Vec<String> {
+    // Build output with Y-axis labels
+    let mut lines = Vec::new();
+    
+    if config.show_labels {
+        let labels = y_labels(min, max, config);
+        let label_width = label_width(min, max, config);
+
+        for (row, label) in canvas.iter().zip(labels) {
+            let label = match label {
+                Some(text) => format!("{:>width$}", text, width = label_width),
+                None => " ".repeat(label_width),
+            };
+
+            lines.push(format!(
+                "{}{}{}",
+                config.label_style.paint(&label),
+                config.axis_style.paint(&config.symbols.axis_vertical.to_string()),
+                render_cells(&row[1..], config)
+            ));
+        }
+    } else {
+        for row in canvas.iter() {
+            lines.push(render_cells(row, config));
+        }
+    }
+
+    lines
+}
+
+/// Render the X-axis line and its tick labels below the chart
+///
+/// `positions` pairs each labelable plotted point with the data index it
+/// shows; point `x` is drawn in canvas column `x / samples_per_column + 1`,
+/// so the axis origin sits under the Y axis (column 0) and ticks line up
+/// with the plotted points. Every `x_label_interval`-th position gets a
+/// tick. Labels start at their tick and are skipped when they would overlap
+/// the previous label or run past the chart.
+fn render_x_axis(positions: &[(usize, usize)], gutter: usize, config: &Config) -> Vec<String> {
+    let columns = config.width.saturating_sub(1);
+    let per_column = config.render_mode.samples_per_column();
+    let visible: Vec<(usize, usize)> =
+        positions.iter().copied().take_while(|&(x, _)| x < columns * per_column).collect();
+    let interval = config.x_label_interval.max(1);
+
+    let last = visible.last().map_or(0, |&(_, i)| i + 1);
+    let granularity = config.time_granularity(last);
+    let ticks: Vec<(usize, Option<String>)> = visible
+        .iter()
+        .step_by(interval)
+        .map(|&(x, index)| (x / per_column + 1, config.x_label(index, granularity)))
+        .collect();
+
+    x_axis_rows(&ticks, gutter, config)
+}
+
+/// Render the X-axis line with a tick at each given column, and the tick labels
+fn x_axis_rows(ticks: &[(usize, Option<String>)], gutter: usize, config: &Config) -> Vec<String> {
+    let mut axis: Vec<char> = vec![config.symbols.axis_horizontal; config.width];
+    axis[0] = config.symbols.axis_origin;
+    for &(column, _) in ticks {
+        axis[column] = config.symbols.axis_bottom;
+    }
+    let axis: String = axis.into_iter().collect();
+
+    let mut labels: Vec<char> = vec![' '; config.width];
+    let mut next_free = 0;
+    for (start, label) in ticks {
+        let (start, Some(label)) = (*start, label) else {
+            continue;
+        };
+        let len = label.chars().count();
+        if start < next_free || start + len > config.width {
+            continue;
+        }
+        for (offset, ch) in label.chars().enumerate() {
+            labels[start + offset] = ch;
+        }
+        next_free = start + len + 1;
+    }
+    let labels: String = labels.into_iter().collect();
+
+    vec![
+        format!("{}{}", " ".repeat(gutter), config.axis_style.paint(&axis)),
+        format!("{}{}", " ".repeat(gutter), config.label_style.paint(labels.trim_end())),
+    ]
+}
+
+/// Render a row of cells, wrapping each run of same-series glyphs in its style
+fn render_cells(cells: &[Cell], config: &Config) -> String {
+    let mut out = String::new();
+    let mut run = String::new();
+    let mut run_style = Style::default();
+
+    for cell in cells {
+        let style = match (cell.kind, cell.series) {
+            (_, None) => Style::default(),
+            (CellKind::Line, Some(i)) => config.series_style(i),
+            (CellKind::Fill, Some(i)) if config.dim_fill => {
+                Style { bold: false, dim: true, ..config.series_style(i) }
+            }
+            (CellKind::Fill, Some(i)) => config.series_style(i),
+            (CellKind::Reference, Some(i)) => config.reference_lines[i].style,
+        };
+        if style != run_style && !run.is_empty() {
+            out.push_str(&run_style.paint(&run));
+            run.clear();
+        }
+        run_style = style;
+        run.push(cell.ch);
+    }
+    out.push_str(&run_style.paint(&run));
+
+    out
+}
+
+/// Notation used for a formatted number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Notation {
+    Fixed,
+    Exponent,
+    UpperExponent,
+}
+
+/// Alignment of a formatted number within its width
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// A parsed `label_format` string
+///
+/// The format is literal text around a single Rust-style placeholder:
+/// `prefix{:[align][sign][0][width][,][.precision][type]}suffix`, where
+/// `align` is `<`, `^` or `>`, `sign` is `+` or `-`, `,` (or `_`) groups
+/// thousands, and `type` is `f` (default), `e` or `E`. Braces in the
+/// literal text are written `{{` and `}}`.
+#[derive(Debug, Clone, PartialEq)]
+struct FormatSpec {
+    prefix: String,
+    suffix: String,
+    align: Align,
+    plus: bool,
+    zero: bool,
+    width: usize,
+    separator: Option<char>,
+    precision: Option<usize>,
+    notation: Notation,
+}
+
+impl FormatSpec {
+    fn parse(format: &str) -> Result<Self> {
+        let invalid = |reason: &str| ChartError::InvalidFormat(format!("{:?}: {}", format, reason));
+
+        let mut prefix = String::new();
+        let mut suffix = String::new();
+        let mut spec: Option<String> = None;
+        let mut chars = format.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let literal = if spec.is_some() { &mut suffix } else { &mut prefix };
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    if spec.is_some() {
+                        return Err(invalid("more than one placeholder"));
+                    }
+                    let mut inner = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => inner.push(c),
+                            None => return Err(invalid("unclosed '{'")),
+                        }
+                    }
+                    spec = Some(inner);
+                }
+                '}' => return Err(invalid("unmatched '}'")),
+                c => literal.push(c),
+            }
+        }
+
+        let spec = spec.ok_or_else(|| invalid("missing '{}' placeholder"))?;
+        let mut parsed = FormatSpec {
+            prefix,
+            suffix,
+            align: Align::Right,
+            plus: false,
+            zero: false,
+            width: 0,
+            separator: None,
+            precision: None,
+            notation: Notation::Fixed,
+        };
+
+        if spec.is_empty() {
+            return Ok(parsed);
+        }
+        let Some(spec) = spec.strip_prefix(':') else {
+            return Err(invalid("placeholder must be empty or start with ':'"));
+        };
+
+        let mut rest = spec.chars().peekable();
+        match rest.peek() {
+            Some('<') => parsed.align = Align::Left,
+            Some('^') => parsed.align = Align::Center,
+            Some('>') => parsed.align = Align::Right,
+            _ => {}
+        }
+        if matches!(rest.peek(), Some('<' | '^' | '>')) {
+            rest.next();
+        }
+        match rest.peek() {
+            Some('+') => {
+                parsed.plus = true;
+                rest.next();
+            }
+            Some('-') => {
+                rest.next();
+            }
+            _ => {}
+        }
+        if rest.peek() == Some(&'0') {
+            parsed.zero = true;
+            rest.next();
+        }
+        let mut digits = String::new();
+        while let Some(d) = rest.next_if(|c| c.is_ascii_digit()) {
+            digits.push(d);
+        }
+        if !digits.is_empty() {
+            // `format!` rejects widths and precisions above u16::MAX
+            parsed.width = digits.parse::<u16>().map_err(|_| invalid("width is too large"))?.into();
+        }
+        if let Some(sep) = rest.next_if(|c| *c == ',' || *c == '_') {
+            parsed.separator = Some(sep);
+        }
+        if rest.next_if_eq(&'.').is_some() {
+            let mut digits = String::new();
+            while let Some(d) = rest.next_if(|c| c.is_ascii_digit()) {
+                digits.push(d);
+            }
+            if digits.is_empty() {
+                return Err(invalid("expected digits after '.'"));
+            }
+            parsed.precision = Some(digits.parse::<u16>().map_err(|_| invalid("precision is too large"))?.into());
+        }
+        match rest.next() {
+            None | Some('f') => {}
+            Some('e') => parsed.notation = Notation::Exponent,
+            Some('E') => parsed.notation = Notation::UpperExponent,
+            Some(c) => return Err(invalid(&format!("unsupported format type '{}'", c))),
+        }
+        if rest.next().is_some() {
+            return Err(invalid("unexpected characters after format type"));
+        }
+
+        Ok(parsed)
+    }
+
+    fn format(&self, value: f64) -> String {
+        let magnitude = value.abs();
+        let mut body = match (self.notation, self.precision) {
+            _ if !value.is_finite() => format!("{}", magnitude),
+            (Notation::Fixed, Some(p)) => format!("{:.*}", p, magnitude),
+            (Notation::Fixed, None) => format!("{}", magnitude),
+            (Notation::Exponent, Some(p)) => format!("{:.*e}", p, magnitude),
+            (Notation::Exponent, None) => format!("{:e}", magnitude),
+            (Notation::UpperExponent, Some(p)) => format!("{:.*E}", p, magnitude),
+            (Notation::UpperExponent, None) => format!("{:E}", magnitude),
+        };
+
+        if let (Some(sep), Notation::Fixed, true) = (self.separator, self.notation, value.is_finite()) {
+            body = group_thousands(&body, sep);
+        }
+
+        // Negative zero after rounding is printed without a sign
+        let negative = value < 0.0
+            && (value.is_infinite() || body.chars().any(|c| c.is_ascii_digit() && c != '0'));
+        let sign = if negative {
+            "-"
+        } else if self.plus {
+            "+"
+        } else {
+            ""
+        };
+
+        let len = sign.chars().count() + body.chars().count();
+        let number = if len >= self.width {
+            format!("{}{}", sign, body)
+        } else if self.zero && value.is_finite() {
+            format!("{}{}{}", sign, "0".repeat(self.width - len), body)
+        } else {
+            let number = format!("{}{}", sign, body);
+            match self.align {
+                Align::Left => format!("{:<width$}", number, width = self.width),
+                Align::Center => format!("{:^width$}", number, width = self.width),
+                Align::Right => format!("{:>width$}", number, width = self.width),
+            }
+        };
+
+        format!("{}{}{}", self.prefix, number, self.suffix)
+    }
+}
+
+/// Insert a separator between groups of three digits in the integer part
+fn group_thousands(number: &str, separator: char) -> String {
+    let (int_part, rest) = match number.find('.') {
+        Some(idx) => number.split_at(idx),
+        None => (number, ""),
+    };
+
+    let digits: Vec<char> = int_part.chars().collect();
+    let mut grouped = String::with_capacity(number.len() + digits.len() / 3);
+    for (i, d) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(*d);
+    }
+    grouped.push_str(rest);
+    grouped
+}
+
+/// SI prefixes from nano to tera, paired with their power of 1000
+const SI_PREFIXES: [(i32, &str); 8] = [
+    (-3, "n"),
+    (-2, "µ"),
+    (-1, "m"),
+    (0, ""),
+    (1, "k"),
+    (2, "M"),
+    (3, "G"),
+    (4, "T"),
+];
+
+/// IEC binary prefixes, paired with their power of 1024
+const BINARY_PREFIXES: [(i32, &str); 6] = [
+    (0, ""),
+    (1, "Ki"),
+    (2, "Mi"),
+    (3, "Gi"),
+    (4, "Ti"),
+    (5, "Pi"),
+];
+
+/// Format a value scaled to the largest prefix that keeps it at or above 1
+///
+/// Produces e.g. `"1.50 kB/s"`, `"3.00 MiB"`, or `"12.00"` when neither a
+/// prefix nor a unit applies. Precision is capped at `u16::MAX`, the most
+/// `format!` accepts.
+fn format_scaled(value: f64, base: f64, prefixes: &[(i32, &str)], unit: &str, precision: usize) -> String {
+    if !value.is_finite() {
+        return format!("{}", value);
+    }
+    let precision = precision.min(usize::from(u16::MAX));
+
+    let lowest = prefixes.first().map(|p| p.0).unwrap_or(0);
+    let highest = prefixes.last().map(|p| p.0).unwrap_or(0);
+    let mut power = if value == 0.0 {
+        0
+    } else {
+        (value.abs().ln() / base.ln()).floor() as i32
+    };
+    power = power.clamp(lowest, highest);
+
+    let mut scaled = value / base.powi(power);
+    // Rounding can push e.g. 999.996 up to "1000.00"; move to the next prefix
+    let rounded = format!("{:.*}", precision, scaled.abs()).parse::<f64>().unwrap_or(0.0);
+    if rounded >= base && power < highest {
+        power += 1;
+        scaled = value / base.powi(power);
+    }
+
+    let prefix = prefixes.iter().find(|p| p.0 == power).map(|p| p.1).unwrap_or("");
+    let suffix = format!("{}{}", prefix, unit);
+    let number = format_value(scaled, &format!("{{:.{}}}", precision));
+    if suffix.is_empty() {
+        number
+    } else {
+        format!("{} {}", number, suffix)
+    }
+}
+
+/// Format a value according to the format string
+///
+/// Invalid formats are rejected by [`Config::validate`]; if one slips
+/// through anyway the value is printed with two decimals.
+fn format_value(value: f64, format: &str) -> String {
+    match FormatSpec::parse(format) {
+        Ok(spec) => spec.format(value),
+        Err(_) => format!("{:.2}", value),
+    }
+}
+
+/// Ordering of categories in a horizontal bar chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarSort {
+    /// Keep the input order
+    #[default]
+    None,
+    /// Smallest value first
+    Ascending,
+    /// Largest value first
+    Descending,
+}
+
+/// Left-block glyphs covering 1/8 to 7/8 of a cell from the left
+const LEFT_BLOCKS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// Horizontal bar chart with a category label on each row
+///
+/// Each row is `name │bar value`: names are left-aligned and truncated to
+/// `config.category_width` (if set), values are formatted like Y-axis
+/// labels and printed right after the bar, and bars fill whatever is left
+/// of `config.width` once the widest value is allowed for. Bars start at
+/// `config.min` (zero by default) and reach the full width at `config.max`
+/// (the largest value by default); values at or below the start get an
+/// empty bar. With the default `█` glyph, bar ends use eighth blocks; ASCII
+/// symbols round to whole cells.
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{hbar_chart_with_config, BarSort, Config};
+///
+/// let latency = [("/api/users", 120.0), ("/api/orders", 340.5), ("/health", 2.0)];
+/// let config = Config::new()
+///     .with_width(50)
+///     .with_label_format("{:.0} ms".to_string())
+///     .with_bar_sort(BarSort::Descending);
+/// println!("{}", hbar_chart_with_config(&latency, config).unwrap());
+/// ```
+pub fn hbar_chart_with_config(items: &[(&str, f64)], config: Config) -> Result<String> {
+    config.validate()?;
+
+    if items.is_empty() {
+        return Err(ChartError::EmptyData);
+    }
+
+    let mut items = items.to_vec();
+    match config.bar_sort {
+        BarSort::None => {}
+        // NaN values always sort last
+        BarSort::Ascending => items.sort_by(|a, b| a.1.is_nan().cmp(&b.1.is_nan()).then(a.1.total_cmp(&b.1))),
+        BarSort::Descending => items.sort_by(|a, b| a.1.is_nan().cmp(&b.1.is_nan()).then(b.1.total_cmp(&a.1))),
+    }
+
+    let values: Vec<f64> = items.iter().map(|item| item.1).collect();
+    let (_, data_max) = finite_bounds(&[&values]).ok_or(ChartError::InvalidRange)?;
+    let start = config.min.unwrap_or(0.0);
+    let end = config.max.unwrap_or(data_max);
+    if end <= start {
+        return Err(ChartError::InvalidRange);
+    }
+
+    let names: Vec<String> = items
+        .iter()
+        .map(|(name, _)| match config.category_width {
+            Some(width) if name.chars().count() > width => {
+                let keep = width.saturating_sub(2);
+                name.chars().take(keep).chain("..".chars()).take(width).collect()
+            }
+            _ => name.to_string(),
+        })
+        .collect();
+    let name_width = names.iter().map(|n| n.chars().count()).max().unwrap_or(0);
+
+    let labels: Vec<String> = values.iter().map(|&v| config.format_label(v)).collect();
+    let value_width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+
+    // name + axis + bar + space + value must fit in the configured width
+    let bar_width = config.width.saturating_sub(name_width + value_width + 2);
+    if bar_width == 0 {
+        return Err(ChartError::InvalidDimensions);
+    }
+
+    let partial = config.symbols.bar == '█';
+    let lines: Vec<String> = names
+        .iter()
+        .zip(&values)
+        .zip(&labels)
+        .map(|((name, &value), label)| {
+            let eighths = if value.is_finite() {
+                ((value.min(end) - start) / (end - start) * (bar_width * 8) as f64).round().max(0.0) as usize
+            } else {
+                0
+            };
+
+            let mut bar = config.symbols.bar.to_string().repeat(eighths / 8);
+            match eighths % 8 {
+                0 => {}
+                rest if partial => bar.push(LEFT_BLOCKS[rest - 1]),
+                rest if rest >= 4 => bar.push(config.symbols.bar),
+                _ => {}
+            }
+
+            format!(
+                "{}{}{} {}",
+                config.label_style.paint(&format!("{:<width$}", name, width = name_width)),
+                config.axis_style.paint(&config.symbols.axis_vertical.to_string()),
+                config.series_style(0).paint(&bar),
+                config.label_style.paint(label)
+            )
+            .trim_end()
+            .to_string()
+        })
+        .collect();
+
+    Ok(lines.join("\n"))
+}
+
+/// Horizontal bar chart with default config
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::hbar_chart;
+///
+/// println!("{}", hbar_chart(&[("alpha", 3.0), ("beta", 5.0), ("gamma", 1.5)]));
+/// ```
+pub fn hbar_chart(items: &[(&str, f64)]) -> String {
+    hbar_chart_with_config(items, Config::default()).unwrap_or_else(|e| e.to_string())
+}
+
+// ============================================================================
+// Histograms
+// ============================================================================
+
+/// Most bins a histogram may have; more is an `InvalidDimensions` error
+const MAX_BINS: usize = 10_000;
+
+/// How samples are grouped into histogram bins
+///
+/// A strategy that would produce more than 10,000 bins is rejected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinStrategy {
+    /// A fixed number of equal-width bins
+    Count(usize),
+    /// Equal-width bins of the given width, starting at the smallest sample
+    Width(f64),
+    /// Sturges' rule: `ceil(log2(n)) + 1` bins
+    Sturges,
+    /// Freedman–Diaconis rule: bin width `2 * IQR / n^(1/3)`
+    /// (falls back to Sturges when the IQR is zero)
+    FreedmanDiaconis,
+}
+
+/// Direction histogram bars are drawn in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// Columns rising from the bottom, bin edges labelled underneath
+    #[default]
+    Vertical,
+    /// One row per bin, labelled with its `[start, end)` range
+    Horizontal,
+}
+
+/// Binned counts of a set of samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Bin edges; bin `i` covers `edges[i]..edges[i + 1]` (the last bin is closed)
+    pub edges: Vec<f64>,
+    /// Number of samples in each bin
+    pub counts: Vec<usize>,
+    /// Number of NaN/infinite samples that were left out
+    pub discarded: usize,
+}
+
+impl Histogram {
+    /// Bin the finite samples using the given strategy
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rasciichart::{BinStrategy, Histogram};
+    ///
+    /// let samples = vec![1.0, 2.0, 2.5, 3.0, f64::NAN, 9.0];
+    /// let hist = Histogram::new(&samples, BinStrategy::Count(4)).unwrap();
+    /// assert_eq!(hist.counts, vec![3, 1, 0, 1]);
+    /// assert_eq!(hist.discarded, 1);
+    /// ```
+    pub fn new(samples: &[f64], strategy: BinStrategy) -> Result<Self> {
+        let mut finite: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+        let discarded = samples.len() - finite.len();
+
+        if finite.is_empty() {
+            return Err(ChartError::EmptyData);
+        }
+        finite.sort_by(f64::total_cmp);
+
+        let min = finite[0];
+        let max = finite[finite.len() - 1];
+
+        // A single distinct value gets one unit-wide bin centred on it
+        if max - min <= 0.0 {
+            return Ok(Self {
+                edges: vec![min - 0.5, min + 0.5],
+                counts: vec![finite.len()],
+                discarded,
+            });
+        }
+
+        // Finite samples can still be too far apart to subtract
+        let range = max - min;
+        if !range.is_finite() {
+            return Err(ChartError::InvalidRange);
+        }
+        let sturges = (finite.len() as f64).log2().ceil() as usize + 1;
+        let bins = match strategy {
+            BinStrategy::Count(0) => return Err(ChartError::InvalidDimensions),
+            BinStrategy::Count(n) => n,
+            BinStrategy::Width(w) if !w.is_finite() || w <= 0.0 => return Err(ChartError::InvalidRange),
+            BinStrategy::Width(w) => ((range / w).ceil() as usize).max(1),
+            BinStrategy::Sturges => sturges,
+            BinStrategy::FreedmanDiaconis => {
+                let iqr = quantile(&finite, 0.75) - quantile(&finite, 0.25);
+                if iqr > 0.0 {
+                    let width = 2.0 * iqr / (finite.len() as f64).cbrt();
+                    ((range / width).ceil() as usize).max(1)
+                } else {
+                    sturges
+                }
+            }
+        };
+        if bins > MAX_BINS {
+            return Err(ChartError::InvalidDimensions);
+        }
+        let width = match strategy {
+            BinStrategy::Width(w) => w,
+            _ => range / bins as f64,
+        };
+
+        let edges: Vec<f64> = (0..=bins).map(|i| min + i as f64 * width).collect();
+        let mut counts = vec![0; bins];
+        for v in finite {
+            let bin = (((v - min) / width).floor() as usize).min(bins - 1);
+            counts[bin] += 1;
+        }
+
+        Ok(Self { edges, counts, discarded })
+    }
+
+    /// Render the histogram as bars
+    ///
+    /// Counts are labelled as whole numbers. Bin edges are formatted with
+    /// `config.x_label_formatter` if set, otherwise with `label_format`. The
+    /// samples are already binned, so `config.min` / `config.max` are ignored
+    /// and the count axis always runs from zero to the largest count. When
+    /// samples were discarded, a final line reports how many.
+    pub fn render(&self, orientation: Orientation, config: Config) -> Result<String> {
+        config.validate()?;
+
+        let format_edge = |edge: f64| match &config.x_label_formatter {
+            Some(formatter) => formatter.format(edge),
+            None => format_value(edge, &config.label_format),
+        };
+        let counts: Vec<f64> = self.counts.iter().map(|&c| c as f64).collect();
+        let count_config = Config {
+            label_format: "{:.0}".to_string(),
+            label_formatter: None,
+            scale: Scale::Linear,
+            min: None,
+            max: None,
+            ..config.clone()
+        };
+
+        let mut lines = match orientation {
+            Orientation::Vertical => {
+                let (mut lines, gutter) = bar_lines(&counts, &count_config)?;
+
+                // Label bin edges under the left side of each bar, plus the final edge
+                let pitch = config.bar_width + config.bar_gap;
+                let mut row: Vec<char> = vec![' '; config.width];
+                let mut next_free = 0;
+                for (i, &edge) in self.edges.iter().enumerate() {
+                    let start = if i < self.counts.len() { 1 + i * pitch } else { 1 + i * pitch - config.bar_gap };
+                    let label = format_edge(edge);
+                    let len = label.chars().count();
+                    if start < next_free || start + len > config.width {
+                        continue;
+                    }
+                    for (offset, ch) in label.chars().enumerate() {
+                        row[start + offset] = ch;
+                    }
+                    next_free = start + len + 1;
+                }
+                let row: String = row.into_iter().collect();
+                lines.push(format!("{}{}", " ".repeat(gutter), config.label_style.paint(row.trim_end())));
+                lines
+            }
+            Orientation::Horizontal => {
+                let last = self.counts.len() - 1;
+                let names: Vec<String> = self
+                    .edges
+                    .windows(2)
+                    .enumerate()
+                    .map(|(i, pair)| {
+                        let close = if i == last { ']' } else { ')' };
+                        format!("[{}, {}{}", format_edge(pair[0]), format_edge(pair[1]), close)
+                    })
+                    .collect();
+                let items: Vec<(&str, f64)> = names.iter().map(|n| n.as_str()).zip(counts).collect();
+                hbar_chart_with_config(&items, count_config)?
+                    .lines()
+                    .map(|line| line.to_string())
+                    .collect()
+            }
+        };
+
+        match self.discarded {
+            0 => {}
+            1 => lines.push("1 non-finite sample discarded".to_string()),
+            n => lines.push(format!("{} non-finite samples discarded", n)),
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Linearly interpolated quantile of sorted values, `q` in `0.0..=1.0`
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+}
+
+/// Bin raw samples and render them as a histogram
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{histogram_with_config, BinStrategy, Config, Orientation};
+///
+/// let durations = vec![12.0, 15.0, 14.0, 30.0, 18.0, 22.0, 13.0, 16.0];
+/// let config = Config::new().with_height(6).with_width(40).with_label_format("{:.0}".to_string());
+/// let chart = histogram_with_config(&durations, BinStrategy::Sturges, Orientation::Vertical, config).unwrap();
+/// println!("{}", chart);
+/// ```
+pub fn histogram_with_config(
+    samples: &[f64],
+    strategy: BinStrategy,
+    orientation: Orientation,
+    config: Config,
+) -> Result<String> {
+    Histogram::new(samples, strategy)?.render(orientation, config)
+}
+
+/// Vertical histogram using Sturges' rule and default config
+pub fn histogram(samples: &[f64]) -> String {
+    histogram_with_config(samples, BinStrategy::Sturges, Orientation::Vertical, Config::default())
+        .unwrap_or_else(|e| e.to_string())
+}
+
+// ============================================================================
+// Candlestick Charts
+// ============================================================================
+
+/// Candlestick (OHLC) chart, one candle per `(open, high, low, close)` period
+///
+/// Wicks span high to low with `symbols.candle_wick`; bodies span open to
+/// close with `symbols.candle_body`. Candles are `config.bar_width` columns
+/// wide (the wick runs down the middle) with `config.bar_gap` blank columns
+/// between them. Rising candles (close >= open) use `series_styles[0]` and
+/// falling ones `series_styles[1]`; see [`Config::with_candle_colors`].
+/// Rows map to prices exactly as in [`plot_with_config`], so the Y-axis
+/// labels are shared. Periods with any non-finite price are left empty.
+///
+/// # Errors
+///
+/// [`ChartError::InvalidRange`] if a period's high is below its open/close
+/// or its low is above them.
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{candlestick_with_config, Color, Config};
+///
+/// let candles = [
+///     (100.0, 104.0, 99.0, 103.0),
+///     (103.0, 105.0, 98.0, 99.5),
+///     (99.5, 102.0, 97.0, 101.0),
+/// ];
+/// let config = Config::new().with_height(10).with_candle_colors(Color::Green, Color::Red);
+/// println!("{}", candlestick_with_config(&candles, config).unwrap());
+/// ```
+pub fn candlestick_with_config(candles: &[(f64, f64, f64, f64)], config: Config) -> Result<String> {
+    config.validate()?;
+
+    if candles.is_empty() {
+        return Err(ChartError::EmptyData);
+    }
+
+    let finite = |c: &&(f64, f64, f64, f64)| {
+        c.0.is_finite() && c.1.is_finite() && c.2.is_finite() && c.3.is_finite()
+    };
+    for &(open, high, low, close) in candles.iter().filter(finite) {
+        if high < open.max(close) || low > open.min(close) {
+            return Err(ChartError::InvalidRange);
+        }
+    }
+
+    let config = Config { scale: Scale::Linear, ..config };
+    let highs: Vec<f64> = candles.iter().filter(finite).map(|c| c.1).collect();
+    let lows: Vec<f64> = candles.iter().filter(finite).map(|c| c.2).collect();
+    let (min, max) = series_bounds(&[&highs, &lows], &config)?;
+
+    // Handle case where all prices are the same
+    if (max - min).abs() < f64::EPSILON {
+        return Ok(config.format_label(min));
+    }
+
+    let height = config.height;
+    let ratio = (height as f64) / (max - min);
+    let to_row = |v: f64| (((max - v) * ratio).round().max(0.0) as usize).min(height);
+
+    let mut canvas: Vec<Vec<Cell>> = vec![vec![Cell::BLANK; config.width]; height + 1];
+    let pitch = config.bar_width + config.bar_gap;
+
+    for (i, candle) in candles.iter().enumerate() {
+        let start = 1 + i * pitch;
+        if start + config.bar_width > config.width {
+            break;
+        }
+        if !finite(&candle) {
+            continue;
+        }
+
+        let &(open, high, low, close) = candle;
+        let series = if close >= open { 0 } else { 1 };
+        let (body_top, body_bottom) = (to_row(open.max(close)), to_row(open.min(close)));
+        let wick = start + config.bar_width / 2;
+
+        for (row, cells) in canvas.iter_mut().enumerate().take(to_row(low) + 1).skip(to_row(high)) {
+            if (body_top..=body_bottom).contains(&row) {
+                for cell in &mut cells[start..start + config.bar_width] {
+                    *cell = Cell::new(config.symbols.candle_body, series);
+                }
+            } else {
+                cells[wick] = Cell::new(config.symbols.candle_wick, series);
+            }
+        }
+    }
+
+    Ok(render_canvas(&canvas, min, max, &config).join("\n"))
+}
+
+/// Candlestick chart with default config
+pub fn candlestick(candles: &[(f64, f64, f64, f64)]) -> String {
+    candlestick_with_config(candles, Config::default()).unwrap_or_else(|e| e.to_string())
+}
+
+// ============================================================================
+// Scatter Plots
+// ============================================================================
+
+/// Scatter plot of explicit `(x, y)` points
+///
+/// The X and Y ranges are computed independently from the finite points, or
+/// fixed with `config.x_min` / `config.x_max` and `config.min` / `config.max`;
+/// points outside a fixed range are not drawn. Points are marked with
+/// `symbols.marker` in `series_styles[0]`, or as single dots in braille and
+/// half-block render modes. The Y axis is labelled as in [`plot_with_config`]
+/// (log scales included), and an X axis with nice tick values about every
+/// `config.x_label_interval` columns is always drawn below. X labels use
+/// `config.x_label_formatter` if set, otherwise `label_format`.
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{scatter_with_config, Config, RenderMode};
+///
+/// let points: Vec<(f64, f64)> = (0..50).map(|i| i as f64 * 0.3).map(|x| (x, x.sin())).collect();
+/// let config = Config::new().with_width(60).with_render_mode(RenderMode::Braille);
+/// println!("{}", scatter_with_config(&points, config).unwrap());
+/// ```
+pub fn scatter_with_config(points: &[(f64, f64)], config: Config) -> Result<String> {
+    config.validate()?;
+
+    if points.is_empty() {
+        return Err(ChartError::EmptyData);
+    }
+
+    let columns = config.width.saturating_sub(1);
+    if columns == 0 {
+        return Err(ChartError::InvalidDimensions);
+    }
+
+    // Y values go through the scale like any series; X is always linear
+    let ys: Vec<f64> = points.iter().map(|p| p.1).collect();
+    let ys = match apply_scale(&[&ys], &config)? {
+        Some(mut scaled) => scaled.remove(0),
+        None => ys,
+    };
+    let points: Vec<(f64, f64)> = points
+        .iter()
+        .map(|p| p.0)
+        .zip(ys)
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+
+    let xs: Vec<f64> = points.iter().map(|p| p.0).collect();
+    let ys: Vec<f64> = points.iter().map(|p| p.1).collect();
+    let (min, max) = widen_flat(series_bounds(&[&ys], &config)?);
+    let (data_x_min, data_x_max) = finite_bounds(&[&xs]).ok_or(ChartError::InvalidRange)?;
+    let x_bounds = (config.x_min.unwrap_or(data_x_min), config.x_max.unwrap_or(data_x_max));
+    let (x_min, x_max) = widen_flat(x_bounds);
+    if x_min > x_max {
+        return Err(ChartError::InvalidRange);
+    }
+
+    let mut canvas: Vec<Vec<Cell>> = vec![vec![Cell::BLANK; config.width]; config.height + 1];
+    let height = config.height as f64;
+    let last_column = (columns - 1) as f64;
+
+    for &(x, y) in &points {
+        // Fractions across the plot, left to right and top to bottom
+        let fx = (x - x_min) / (x_max - x_min);
+        let fy = (max - y) / (max - min);
+        if !(0.0..=1.0).contains(&fx) || !(0.0..=1.0).contains(&fy) {
+            continue;
+        }
+
+        match config.render_mode {
+            RenderMode::Lines => {
+                let column = 1 + (fx * last_column).round() as usize;
+                canvas[(fy * height).round() as usize][column] = Cell::new(config.symbols.marker, 0);
+            }
+            RenderMode::Braille => {
+                let dx = (fx * (columns * 2 - 1) as f64).round() as usize;
+                let dy = (fy * height * 4.0 + 1.5).round() as usize;
+                set_braille_dot(&mut canvas, dx, dy, 0);
+            }
+            RenderMode::HalfBlock | RenderMode::HalfBlockFilled => {
+                let column = 1 + (fx * last_column).round() as usize;
+                set_half_block(&mut canvas, column, (fy * (height * 2.0 + 1.0)).round() as usize, 0);
+            }
+        }
+    }
+
+    let mut lines = render_canvas(&canvas, min, max, &config);
+    let gutter = if config.show_labels {
+        label_width(min, max, &config)
+    } else {
+        0
+    };
+
+    let intervals = (columns / config.x_label_interval.max(1)).max(1);
+    let ticks: Vec<(usize, Option<String>)> = nice_ticks(x_min, x_max, intervals)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|tick| {
+            let column = 1 + ((tick - x_min) / (x_max - x_min) * last_column).round() as usize;
+            let label = match &config.x_label_formatter {
+                Some(formatter) => formatter.format(tick),
+                None => format_value(tick, &config.label_format),
+            };
+            (column.min(columns), Some(label))
+        })
+        .collect();
+    lines.append(&mut x_axis_rows(&ticks, gutter, &config));
+
+    Ok(lines.join("\n"))
+}
+
+/// Scatter plot with default config
+pub fn scatter(points: &[(f64, f64)]) -> String {
+    scatter_with_config(points, Config::default()).unwrap_or_else(|e| e.to_string())
+}
+
+/// Widen an empty range by one unit either side so it can be mapped
+fn widen_flat((min, max): (f64, f64)) -> (f64, f64) {
+    if (max - min).abs() < f64::EPSILON {
+        (min - 1.0, max + 1.0)
+    } else {
+        (min, max)
+    }
+}
+
+// ============================================================================
+// Sparklines
+// ============================================================================
+
+/// Eighth-block levels used by sparklines, lowest to highest
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// ASCII stand-ins for [`SPARK_LEVELS`]
+const SPARK_LEVELS_ASCII: [char; 8] = ['_', '.', '-', ':', '=', '+', '*', '#'];
+
+/// Configuration for one-line sparklines
+#[derive(Debug, Clone)]
+pub struct SparklineConfig {
+    /// Value drawn at the lowest level (auto-calculated if None)
+    pub min: Option<f64>,
+    /// Value drawn at the highest level (auto-calculated if None)
+    pub max: Option<f64>,
+    /// Output width in characters (one per value if None)
+    pub width: Option<usize>,
+    /// Character drawn for NaN/infinite values and empty buckets
+    pub gap: char,
+    /// Use ASCII characters instead of block elements
+    pub ascii: bool,
+}
+
+impl Default for SparklineConfig {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            width: None,
+            gap: ' ',
+            ascii: false,
+        }
+    }
+}
+
+impl SparklineConfig {
+    /// Create a new SparklineConfig with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value drawn at the lowest level
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the value drawn at the highest level
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Set the output width
+    ///
+    /// Longer series are downsampled by averaging buckets of values;
+    /// shorter ones are padded with spaces on the right.
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the character drawn for missing values
+    pub fn with_gap(mut self, gap: char) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Use ASCII-only characters
+    pub fn with_ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<()> {
+        if self.width == Some(0) {
+            return Err(ChartError::InvalidDimensions);
+        }
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min >= max {
+                return Err(ChartError::InvalidRange);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Render a series as a one-line sparkline with custom options
+///
+/// Non-finite values are drawn with the gap character and ignored when
+/// computing the range. A flat series (or a min/max that leave no range)
+/// is drawn at the lowest level, and values outside a fixed min/max are
+/// clamped to the nearest level.
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{sparkline_with_config, SparklineConfig};
+///
+/// let data = vec![1.0, 5.0, f64::NAN, 3.0, 8.0];
+/// let config = SparklineConfig::new().with_gap('·').with_min(0.0);
+/// println!("{}", sparkline_with_config(&data, config).unwrap());
+/// ```
+pub fn sparkline_with_config(series: &[f64], config: SparklineConfig) -> Result<String> {
+    config.validate()?;
+
+    if series.is_empty() {
+        return Err(ChartError::EmptyData);
+    }
+
+    let values = match config.width {
+        Some(width) if series.len() > width => bucket_means(series, width),
+        _ => series.to_vec(),
+    };
+
+    let (data_min, data_max) = finite_bounds(&[series]).unwrap_or((0.0, 0.0));
+    let min = config.min.unwrap_or(data_min);
+    let max = config.max.unwrap_or(data_max);
+    if !min.is_finite() || !max.is_finite() {
+        return Err(ChartError::InvalidRange);
+    }
+
+    let levels = if config.ascii { &SPARK_LEVELS_ASCII } else { &SPARK_LEVELS };
+    let top = (levels.len() - 1) as f64;
+
+    let mut line: String = values
+        .iter()
+        .map(|&v| {
+            if !v.is_finite() {
+                config.gap
+            } else if max <= min {
+                levels[0]
+            } else {
+                let level = ((v - min) / (max - min) * top).round().clamp(0.0, top);
+                levels[level as usize]
+            }
+        })
+        .collect();
+
+    if let Some(width) = config.width {
+        line.extend(std::iter::repeat_n(' ', width.saturating_sub(values.len())));
+    }
+
+    Ok(line)
+}
+
+/// Render a series as a one-line sparkline
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::sparkline;
+///
+/// assert_eq!(sparkline(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]), "▁▂▃▄▅▆▇█");
+/// ```
+pub fn sparkline(series: &[f64]) -> String {
+    sparkline_with_config(series, SparklineConfig::default()).unwrap_or_else(|e| e.to_string())
+}
+
+/// Render a sparkline using ASCII characters only
+pub fn sparkline_ascii(series: &[f64]) -> String {
+    sparkline_with_config(series, SparklineConfig::default().with_ascii()).unwrap_or_else(|e| e.to_string())
+}
+
+/// Average `series` into `buckets` evenly sized buckets, skipping non-finite values
+///
+/// A bucket with no finite values becomes NaN so it is drawn as a gap.
+fn bucket_means(series: &[f64], buckets: usize) -> Vec<f64> {
+    (0..buckets)
+        .map(|b| {
+            let start = b * series.len() / buckets;
+            let end = ((b + 1) * series.len() / buckets).max(start + 1);
+            let finite: Vec<f64> = series[start..end].iter().copied().filter(|v| v.is_finite()).collect();
+            if finite.is_empty() {
+                f64::NAN
+            } else {
+                finite.iter().sum::<f64>() / finite.len() as f64
+            }
+        })
+        .collect()
+}
+
+// ============================================================================
+// Bar Charts
+// ============================================================================
+
+/// Upper-block glyph covering roughly `eighths` of a cell from the top
+///
+/// Unicode only has 1/8 and 1/2 upper blocks, so hanging (negative) bars
+/// have about half-cell precision at their tip.
+fn upper_block(eighths: usize) -> char {
+    match eighths {
+        0..=2 => '▔',
+        3..=5 => '▀',
+        _ => '█',
+    }
+}
+
+/// Vertical bar chart: one bar per value, drawn up (or down) from zero
+///
+/// Bars are `config.bar_width` columns wide with `config.bar_gap` blank
+/// columns between them, and share the Y-axis labels of
+/// [`plot_with_config`]. The range always includes zero, so negative values
+/// hang below a zero baseline. With the default `█` bar glyph, bar ends use
+/// eighth blocks for sub-cell precision; a custom glyph (e.g. ASCII `#`)
+/// rounds to whole cells. Non-finite values leave an empty slot, and bars
+/// that do not fit in `config.width` are dropped. The Y axis is always
+/// linear, and a fixed `min` / `max` that leaves no room for the data is an
+/// `InvalidRange` error.
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{bar_chart_with_config, Config};
+///
+/// let data = vec![3.0, -1.5, 4.25, 2.0];
+/// let config = Config::new().with_height(8).with_bar_width(2);
+/// println!("{}", bar_chart_with_config(&data, config).unwrap());
+/// ```
+pub fn bar_chart_with_config(values: &[f64], config: Config) -> Result<String> {
+    config.validate()?;
+
+    if values.is_empty() {
+        return Err(ChartError::EmptyData);
+    }
+
+    let config = Config { scale: Scale::Linear, ..config };
+    let (lines, _) = bar_lines(values, &config)?;
+    Ok(lines.join("\n"))
+}
+
+/// Render vertical bars, returning the output rows and the label gutter width
+fn bar_lines(values: &[f64], config: &Config) -> Result<(Vec<String>, usize)> {
+    let (data_min, data_max) = finite_bounds(&[values]).ok_or(ChartError::InvalidRange)?;
+    let min = config.min.unwrap_or(data_min.min(0.0));
+    let max = config.max.unwrap_or(data_max.max(0.0));
+
+    if !min.is_finite() || !max.is_finite() {
+        return Err(ChartError::InvalidRange);
+    }
+
+    // Handle case where all values are zero
+    if (max - min).abs() < f64::EPSILON && config.min.is_none() && config.max.is_none() {
+        return Ok((vec![config.format_label(min)], 0));
+    }
+
+    // A one-sided fixed bound can land on the wrong side of the data
+    if min >= max {
+        return Err(ChartError::InvalidRange);
+    }
+
+    let rows = config.height + 1;
+    let mut canvas: Vec<Vec<Cell>> = vec![vec![Cell::BLANK; config.width]; rows];
+
+    // Positions are measured in eighths of a cell from the top of the chart.
+    // Values map to rows as in `render_canvas`, so a value sits in the middle
+    // of the row its label is on.
+    let scale = config.height as f64 / (max - min);
+    let to_eighths = |v: f64| (((max - v.clamp(min, max)) * scale + 0.5) * 8.0).round() as usize;
+    let base_row = (to_eighths(0.0) + 4) / 8;
+    let base = base_row * 8;
+    let partial = config.symbols.bar == '█';
+
+    for (i, &value) in values.iter().enumerate() {
+        let start = 1 + i * (config.bar_width + config.bar_gap);
+        if start + config.bar_width > config.width {
+            break;
+        }
+        if !value.is_finite() {
+            continue;
+        }
+
+        let tip = to_eighths(value);
+        let mut column: Vec<(usize, char)> = Vec::new();
+
+        if tip < base {
+            // Positive bar: filled from the baseline up, partial cell at the top
+            for row in tip / 8..base_row {
+                let filled = base.min(row * 8 + 8) - tip.max(row * 8);
+                let glyph = match filled {
+                    8 => Some(config.symbols.bar),
+                    f if partial => Some(SPARK_LEVELS[f - 1]),
+                    f if f >= 4 => Some(config.symbols.bar),
+                    _ => None,
+                };
+                column.extend(glyph.map(|g| (row, g)));
+            }
+        } else if tip > base {
+            // Negative bar: hangs from the baseline down, partial cell at the bottom
+            for row in base_row..tip.div_ceil(8) {
+                let filled = tip.min(row * 8 + 8) - base.max(row * 8);
+                let glyph = match filled {
+                    8 => Some(config.symbols.bar),
+                    f if partial => Some(upper_block(f)),
+                    f if f >= 4 => Some(config.symbols.bar),
+                    _ => None,
+                };
+                column.extend(glyph.map(|g| (row, g)));
+            }
+        }
+
+        for (row, glyph) in column {
+            for cell in &mut canvas[row][start..start + config.bar_width] {
+                *cell = Cell::new(glyph, 0);
+            }
+        }
+    }
+
+    let gutter = if config.show_labels { label_width(min, max, config) } else { 0 };
+    Ok((render_canvas(&canvas, min, max, config), gutter))
+}
+
+/// Vertical bar chart with default config
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::bar_chart;
+///
+/// let data = vec![3.0, 1.0, 4.0, 1.0, 5.0];
+/// println!("{}", bar_chart(&data));
+/// ```
+pub fn bar_chart(values: &[f64]) -> String {
+    bar_chart_with_config(values, Config::default()).unwrap_or_else(|e| e.to_string())
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Simple plot function with default config
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::plot;
+///
+/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 4.0, 3.0, 2.0, 1.0];
+/// println!("{}", plot(&data));
+/// ```
+pub fn plot(series: &[f64]) -> String {
+    plot_with_config(series, Config::default()).unwrap_or_else(|e| e.to_string())
+}
+
+/// Plot with custom height and width
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::plot_sized;
+///
+/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// println!("{}", plot_sized(&data, 15, 60));
+/// ```
+pub fn plot_sized(series: &[f64], height: usize, width: usize) -> String {
+    plot_with_config(
+        series,
+        Config::default().with_height(height).with_width(width)
+    ).unwrap_or_else(|e| e.to_string())
+}
+
+/// Plot without Y-axis labels
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::plot_no_labels;
+///
+/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// println!("{}", plot_no_labels(&data));
+/// ```
+pub fn plot_no_labels(series: &[f64]) -> String {
+    plot_with_config(
+        series,
+        Config::default().with_labels(false)
+    ).unwrap_or_else(|e| e.to_string())
+}
+
+/// Plot with custom min and max values
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::plot_range;
+///
+/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// println!("{}", plot_range(&data, 0.0, 10.0));
+/// ```
+pub fn plot_range(series: &[f64], min: f64, max: f64) -> String {
+    plot_with_config(
+        series,
+        Config::default().with_min(min).with_max(max)
+    ).unwrap_or_else(|e| e.to_string())
+}
+
+/// Plot using ASCII-only characters for better compatibility
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::plot_ascii;
+///
+/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// println!("{}", plot_ascii(&data));
+/// ```
+pub fn plot_ascii(series: &[f64]) -> String {
+    plot_with_config(
+        series,
+        Config::default().with_ascii_symbols()
+    ).unwrap_or_else(|e| e.to_string())
+}
+
+/// Plot multiple series on the same chart (overlaid)
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::plot_multiple;
+///
+/// let series1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// let series2 = vec![5.0, 4.0, 3.0, 2.0, 1.0];
+/// println!("{}", plot_multiple(&[&series1, &series2]));
+/// ```
+pub fn plot_multiple(series: &[&[f64]]) -> String {
+    plot_multiple_with_config(series, Config::default()).unwrap_or_else(|e| e.to_string())
+}
+
+/// Plot using braille dots for twice the horizontal and four times the vertical resolution
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{generate_sine, plot_braille};
+///
+/// let data = generate_sine(120, 2.0, 0.0);
+/// println!("{}", plot_braille(&data));
+/// ```
+pub fn plot_braille(series: &[f64]) -> String {
+    plot_with_config(
+        series,
+        Config::default().with_render_mode(RenderMode::Braille)
+    ).unwrap_or_else(|e| e.to_string())
+}
+
+/// Generate sine wave data for testing
+///
+/// # Example
+///
+/// ```rust
+/// use rasciichart::{generate_sine, plot};
+///
+/// let data = generate_sine(50, 1.0, 0.0);
+/// println!("{}", plot(&data));
+/// ```
+pub fn generate_sine(points: usize, frequency: f64, phase: f64) -> Vec<f64> {
+    (0..points)
+        .map(|i| {
+            let x = i as f64 * 2.0 * std::f64::consts::PI / points as f64;
+            (frequency * x + phase).sin()
+        })
+        .collect()
+}
+
+/// Generate cosine wave data for testing
+pub fn generate_cosine(points: usize, frequency: f64, phase: f64) -> Vec<f64> {
+    (0..points)
+        .map(|i| {
+            let x = i as f64 * 2.0 * std::f64::consts::PI / points as f64;
+            (frequency * x + phase).cos()
+        })
+        .collect()
+}
+
+/// Generate random walk data for testing
+pub fn generate_random_walk(points: usize, start: f64, volatility: f64) -> Vec<f64> {
+    use std::collections::hash_map::RandomState;
+    use std::hash::BuildHasher;
+    
+    let mut result = Vec::with_capacity(points);
+    let mut current = start;
+    result.push(current);
+    
+    for i in 1..points {
+        // Simple pseudo-random using hash
+        let hash = RandomState::new().hash_one(i);
+        let random = (hash % 1000) as f64 / 1000.0 - 0.5;
+        
+        current += random * volatility;
+        result.push(current);
+    }
+    
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_plot() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 4.0, 3.0, 2.0, 1.0];
+        let chart = plot(&data);
+        assert!(!chart.is_empty());
+    }
+
+    #[test]
+    fn test_empty_data() {
+        let data: Vec<f64> = vec![];
+        let result = plot_with_config(&data, Config::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_single_value() {
+        let data = vec![5.0];
+        let chart = plot(&data);
+        assert!(chart.contains("5.00"));
+    }
+
+    #[test]
+    fn test_custom_config() {
+        let data = vec![10.0, 20.0, 30.0, 20.0, 10.0];
+        let config = Config::default()
+            .with_height(15)
+            .with_width(50);
+        let chart = plot_with_config(&data, config).unwrap();
+        assert!(!chart.is_empty());
+    }
+
+    #[test]
+    fn test_no_labels() {
+        let data = vec![1.0, 2.0, 3.0];
+        let chart = plot_no_labels(&data);
+        assert!(!chart.is_empty());
+        assert!(!chart.contains("3.00"));
+        // Risers still use '│', but nothing axis-like is drawn in the first
+        // two columns: column 0 is blank and the series starts with '─'
+        let lines: Vec<Vec<char>> = chart.lines().map(|line| line.chars().collect()).collect();
+        assert!(lines.iter().all(|line| line[0] == ' ' && line[1] != '│'));
+        assert_eq!(lines[10][1], '─');
+    }
+
+    #[test]
+    fn test_first_point_glyph() {
+        let config = Config::new().with_height(2).with_width(5).with_label_format("{:.0}".to_string());
+
+        // Next to the axis the start is left to the axis itself
+        let chart = plot_with_config(&[0.0, 2.0, 2.0], config.clone()).unwrap();
+        assert_eq!(chart, "2│ ╭─ \n │ │  \n0│ ╯  ");
+
+        // Without labels, or after leading gaps, the start is a '─' stub
+        let chart = plot_with_config(&[0.0, 2.0, 2.0], config.clone().with_labels(false)).unwrap();
+        assert_eq!(chart, "  ╭─ \n  │  \n ─╯  ");
+        let chart = plot_with_config(&[f64::NAN, 0.0, 2.0], config).unwrap();
+        assert_eq!(chart, "2│  ╭ \n │  │ \n0│ ─╯ ");
+    }
+
+    #[test]
+    fn test_ascii_symbols() {
+        let data = vec![1.0, 2.0, 3.0];
+        let chart = plot_ascii(&data);
+        assert!(!chart.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        let config = Config::default().with_min(10.0).with_max(5.0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_generate_sine() {
+        let data = generate_sine(50, 1.0, 0.0);
+        assert_eq!(data.len(), 50);
+        assert!(data[0].abs() < 0.1);
+    }
+
+    #[test]
+    fn test_with_nan() {
+        let data = vec![1.0, 2.0, f64::NAN, 4.0, 5.0];
+        let chart = plot(&data);
+        assert!(!chart.is_empty());
+    }
+
+    #[test]
+    fn test_with_infinity() {
+        let data = vec![1.0, 2.0, f64::INFINITY, 4.0, 5.0];
+        let chart = plot(&data);
+        assert!(!chart.is_empty());
+    }
+
+    #[test]
+    fn test_small_range() {
+        let data = vec![1.001, 1.002, 1.003, 1.002, 1.001];
+        let chart = plot(&data);
+        assert!(!chart.is_empty());
+        // Should not be excessively tall
+        let line_count = chart.lines().count();
+        assert!(line_count <= 15); // Default height + 1
+    }
+
+    #[test]
+    fn test_ascending_line() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let chart = plot(&data);
+        // Should contain ascending characters
+        assert!(chart.contains("╭") || chart.contains("╰"));
+    }
+
+    #[test]
+    fn test_descending_line() {
+        let data = vec![5.0, 4.0, 3.0, 2.0, 1.0];
+        let chart = plot(&data);
+        // Should contain descending characters
+        assert!(chart.contains("╮") || chart.contains("╯"));
+    }
+
+    #[test]
+    fn test_multiple_series_all_drawn() {
+        let high = vec![5.0, 5.0, 5.0, 5.0];
+        let low = vec![1.0, 1.0, 1.0, 1.0];
+        let chart = plot_multiple(&[&high, &low]);
+        let lines: Vec<&str> = chart.lines().collect();
+        assert!(lines.first().unwrap().contains('─'));
+        assert!(lines.last().unwrap().contains('─'));
+    }
+
+    #[test]
+    fn test_multiple_series_last_wins() {
+        let a = vec![1.0, 3.0, 3.0, 3.0];
+        let b = vec![1.0, 5.0, 5.0, 5.0];
+        let config = Config::new().with_height(4).with_width(10).with_labels(false);
+        let ab = plot_multiple_with_config(&[&a, &b], config.clone()).unwrap();
+        let ba = plot_multiple_with_config(&[&b, &a], config).unwrap();
+        // The overlapping column at x=2 is owned by whichever series is drawn last
+        let row = |chart: &str, idx: usize| chart.lines().nth(idx).unwrap().chars().nth(2).unwrap();
+        assert_eq!(row(&ab, 4), '╯');
+        assert_eq!(row(&ba, 4), '╯');
+        assert_eq!(row(&ab, 2), '│');
+        assert_eq!(row(&ba, 2), '╭');
+    }
+
+    #[test]
+    fn test_multiple_series_empty() {
+        let empty: Vec<f64> = vec![];
+        let result = plot_multiple_with_config(&[&empty], Config::default());
+        assert_eq!(result, Err(ChartError::EmptyData));
+    }
+
+    #[test]
+    fn test_plain_style_emits_no_escapes() {
+        let data = vec![1.0, 2.0, 3.0];
+        assert!(!plot(&data).contains('\x1b'));
+        assert_eq!(Style::new().paint("x"), "x");
+    }
+
+    #[test]
+    fn test_style_sgr_sequences() {
+        assert_eq!(Style::new().fg(Color::Red).paint("x"), "\x1b[31mx\x1b[0m");
+        assert_eq!(Style::new().fg(Color::BrightBlue).bold().paint("x"), "\x1b[1;94mx\x1b[0m");
+        assert_eq!(Style::new().fg(Color::Ansi256(208)).paint("x"), "\x1b[38;5;208mx\x1b[0m");
+        assert_eq!(Style::new().fg(Color::Rgb(1, 2, 3)).dim().paint("x"), "\x1b[2;38;2;1;2;3mx\x1b[0m");
+    }
+
+    #[test]
+    fn test_series_styles() {
+        let a = vec![5.0, 5.0, 5.0];
+        let b = vec![1.0, 1.0, 1.0];
+        let config = Config::new()
+            .with_width(10)
+            .with_series_styles(vec![Color::Red.into(), Color::Green.into()])
+            .with_axis_style(Style::new().dim());
+        let chart = plot_multiple_with_config(&[&a, &b], config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert!(lines[0].contains("\x1b[31m──\x1b[0m"));
+        assert!(lines[lines.len() - 1].contains("\x1b[32m──\x1b[0m"));
+        assert!(lines[0].contains("\x1b[2m│\x1b[0m"));
+    }
+
+    #[test]
+    fn test_legend_bottom() {
+        let a = vec![1.0, 2.0, 3.0];
+        let b = vec![3.0, 2.0, 1.0];
+        let config = Config::new()
+            .with_width(40)
+            .with_series_names(vec!["up".to_string(), "down".to_string()])
+            .with_legend(LegendPosition::Bottom);
+        let chart = plot_multiple_with_config(&[&a, &b], config).unwrap();
+        assert_eq!(chart.lines().last().unwrap(), "── up  ── down");
+    }
+
+    #[test]
+    fn test_legend_top_wraps_to_width() {
+        let a = vec![1.0, 2.0, 3.0];
+        let config = Config::new()
+            .with_width(12)
+            .with_labels(false)
+            .with_legend(LegendPosition::Top);
+        let chart = plot_multiple_with_config(&[&a, &a, &a], config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines[0], "── Series 1");
+        assert_eq!(lines[1], "── Series 2");
+        assert_eq!(lines[2], "── Series 3");
+        assert!(lines.iter().all(|l| l.chars().count() <= 12));
+    }
+
+    #[test]
+    fn test_legend_right_keeps_total_width() {
+        let a = vec![1.0, 2.0, 3.0];
+        let plain = Config::new().with_width(60);
+        let without = plot_multiple_with_config(&[&a], plain.clone()).unwrap();
+        let with = plot_multiple_with_config(
+            &[&a],
+            plain
+                .with_series_names(vec!["a very long series name indeed".to_string()])
+                .with_legend(LegendPosition::Right),
+        )
+        .unwrap();
+        let width = |chart: &str| chart.lines().map(|l| l.chars().count()).max().unwrap();
+        assert_eq!(width(&with), width(&without));
+        assert!(with.lines().next().unwrap().ends_with("── a very long ser.."));
+    }
+
+    #[test]
+    fn test_legend_right_more_series_than_rows() {
+        let series: Vec<Vec<f64>> = (0..5).map(|i| vec![i as f64, i as f64 + 1.0]).collect();
+        let refs: Vec<&[f64]> = series.iter().map(|s| s.as_slice()).collect();
+        let config = Config::new()
+            .with_height(2)
+            .with_width(45)
+            .with_x_axis(true)
+            .with_legend(LegendPosition::Right);
+        let chart = plot_multiple_with_config(&refs, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert!(lines[0].ends_with("── Series 1"));
+        assert!(lines[1].ends_with("── Series 2"));
+        assert!(lines[2].ends_with(" +3 more"));
+        // The X axis rows carry no legend entries
+        assert!(lines[3..].iter().all(|line| !line.contains("Series") && !line.contains("more")));
+    }
+
+    #[test]
+    fn test_x_axis_ticks_align_with_points() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let config = Config::new()
+            .with_height(4)
+            .with_width(10)
+            .with_x_axis(true)
+            .with_x_label_interval(5);
+        let chart = plot_with_config(&data, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[5], "    └┴────┴───");
+        assert_eq!(lines[6], "     0    5");
+        // The last data point and its tick share a column
+        let top: Vec<char> = lines[0].chars().collect();
+        let axis: Vec<char> = lines[5].chars().collect();
+        assert_eq!(top[10], '╭');
+        assert_eq!(axis[10], '┴');
+    }
+
+    #[test]
+    fn test_x_axis_custom_labels_skip_overlaps() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let labels: Vec<String> = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let config = Config::new()
+            .with_width(20)
+            .with_labels(false)
+            .with_x_labels(labels)
+            .with_x_label_interval(2);
+        let chart = plot_with_config(&data, config).unwrap();
+        // "Wed" would touch "Mon", so only every other tick gets a label
+        assert_eq!(chart.lines().last().unwrap(), " Mon Fri");
+
+        let config = Config::new().with_x_axis(true).with_x_label_interval(0);
+        assert_eq!(config.validate(), Err(ChartError::InvalidDimensions));
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(19_723), (2024, 1, 1));
+    }
+
+    #[test]
+    fn test_time_granularity() {
+        assert_eq!(TimeGranularity::for_span(90), TimeGranularity::Seconds);
+        assert_eq!(TimeGranularity::for_span(3 * 3600), TimeGranularity::Minutes);
+        assert_eq!(TimeGranularity::for_span(2 * 86_400), TimeGranularity::Hours);
+        assert_eq!(TimeGranularity::for_span(30 * 86_400), TimeGranularity::Days);
+        assert_eq!(TimeGranularity::for_span(400 * 86_400), TimeGranularity::Months);
+        assert_eq!(TimeGranularity::for_span(3000 * 86_400), TimeGranularity::Years);
+
+        // 2024-03-05 14:07:09 UTC
+        let t = 1_709_647_629;
+        assert_eq!(TimeGranularity::Seconds.format(t), "14:07:09");
+        assert_eq!(TimeGranularity::Hours.format(t), "03-05 14:00");
+        assert_eq!(TimeGranularity::Months.format(t), "2024-03");
+    }
+
+    #[test]
+    fn test_timestamp_x_axis() {
+        let data = vec![1.0, 3.0, 2.0, 4.0];
+        let start = 1_709_647_200_000; // 2024-03-05 14:00:00 UTC, in ms
+        let stamps: Vec<i64> = (0..4).map(|i| start + i * 60_000).collect();
+        let config = Config::new()
+            .with_width(20)
+            .with_labels(false)
+            .with_x_label_interval(3)
+            .with_timestamps(stamps, TimeUnit::Milliseconds);
+        let chart = plot_with_config(&data, config).unwrap();
+        assert_eq!(chart.lines().last().unwrap(), " 14:00:00");
+    }
+
+    #[test]
+    fn test_timestamp_span_does_not_overflow() {
+        assert_eq!(TimeGranularity::for_span(u64::MAX), TimeGranularity::Years);
+        let config = Config::new()
+            .with_width(20)
+            .with_x_axis(true)
+            .with_timestamps(vec![i64::MIN, 0, i64::MAX, 1, 2], TimeUnit::Seconds);
+        assert_eq!(config.time_granularity(5), Some(TimeGranularity::Years));
+        assert!(plot_with_config(&[1.0, 3.0, 2.0, 4.0, 5.0], config).is_ok());
+    }
+
+    #[test]
+    fn test_format_spec_precision_and_sign() {
+        assert_eq!(format_value(1.23456, "{:.3}"), "1.235");
+        assert_eq!(format_value(2.5, "{:+.1}"), "+2.5");
+        assert_eq!(format_value(-2.5, "{:+.1}"), "-2.5");
+        assert_eq!(format_value(-0.001, "{:.1}"), "0.0");
+        assert_eq!(format_value(1.5, "{}"), "1.5");
+        assert_eq!(format_value(1.5, "{:.0}"), "2");
+        assert_eq!(format_value(f64::NEG_INFINITY, "{:.2}"), "-inf");
+    }
+
+    #[test]
+    fn test_format_spec_width_and_notation() {
+        assert_eq!(format_value(1.5, "{:6.1}"), "   1.5");
+        assert_eq!(format_value(1.5, "{:<6.1}|"), "1.5   |");
+        assert_eq!(format_value(-1.5, "{:06.1}"), "-001.5");
+        assert_eq!(format_value(12345.0, "{:.2e}"), "1.23e4");
+        assert_eq!(format_value(12345.0, "{:E}"), "1.2345E4");
+        assert_eq!(format_value(1234567.891, "{:,.2}"), "1,234,567.89");
+        assert_eq!(format_value(-1234.0, "{:_.0}"), "-1_234");
+    }
+
+    #[test]
+    fn test_format_spec_literal_text() {
+        assert_eq!(format_value(9.5, "${:.2}"), "$9.50");
+        assert_eq!(format_value(42.0, "{:.1}%"), "42.0%");
+        assert_eq!(format_value(1.0, "{{{:.0}}}"), "{1}");
+    }
+
+    #[test]
+    fn test_format_spec_rejects_malformed() {
+        for bad in ["", "%.2f", "{:.2", "{:.}", "{:x}", "{0:.2}", "{:.2}{:.2}", "}{:.2}", "{:.2f%}", "{:1000000000.1}", "{:.70000}"] {
+            let config = Config::new().with_label_format(bad.to_string());
+            assert!(
+                matches!(config.validate(), Err(ChartError::InvalidFormat(_))),
+                "{:?} should be rejected",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_label_formatter() {
+        let data = vec![0.0, 30.0, 60.0];
+        let config = Config::new()
+            .with_height(2)
+            .with_width(10)
+            .with_label_formatter(|v| format!("{}m", v as i64));
+        // Config stays Clone + Debug with a formatter attached
+        let cloned = config.clone();
+        assert!(format!("{:?}", cloned).contains("LabelFormatter(..)"));
+
+        let chart = plot_with_config(&data, cloned).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert!(lines[0].starts_with("60m│"));
+        assert!(lines[2].starts_with(" 0m│"));
+    }
+
+    #[test]
+    fn test_x_label_formatter() {
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+        let config = Config::new()
+            .with_width(10)
+            .with_labels(false)
+            .with_x_axis(true)
+            .with_x_label_interval(3)
+            .with_x_label_formatter(|i| format!("#{}", i));
+        let chart = plot_with_config(&data, config).unwrap();
+        assert_eq!(chart.lines().last().unwrap(), " #0 #3");
+    }
+
+    #[test]
+    fn test_si_labels() {
+        let si = LabelFormatter::si("B/s", 2);
+        assert_eq!(si.format(1_500.0), "1.50 kB/s");
+        assert_eq!(si.format(1_048_576.0), "1.05 MB/s");
+        assert_eq!(si.format(0.0025), "2.50 mB/s");
+        assert_eq!(si.format(0.000_003), "3.00 µB/s");
+        assert_eq!(si.format(-2e12), "-2.00 TB/s");
+        assert_eq!(si.format(999_999.0), "1.00 MB/s");
+        assert_eq!(si.format(0.0), "0.00 B/s");
+        assert_eq!(LabelFormatter::si("", 1).format(12.0), "12.0");
+    }
+
+    #[test]
+    fn test_binary_labels() {
+        let bin = LabelFormatter::binary("B", 1);
+        assert_eq!(bin.format(512.0), "512.0 B");
+        assert_eq!(bin.format(1024.0), "1.0 KiB");
+        assert_eq!(bin.format(1_048_576.0), "1.0 MiB");
+        assert_eq!(bin.format(3.5 * 1024.0 * 1024.0 * 1024.0), "3.5 GiB");
+
+        // Huge precisions are capped rather than panicking in `format!`
+        let decimals = |label: String| label.split(['.', ' ']).nth(1).map_or(0, str::len);
+        assert_eq!(decimals(LabelFormatter::si("B", 100_000).format(1.0)), 65_535);
+        assert_eq!(decimals(LabelFormatter::binary("B", usize::MAX).format(1.0)), 65_535);
+    }
+
+    #[test]
+    fn test_prefixed_labels_align() {
+        let data = vec![0.0, 500_000.0, 1_048_576.0];
+        let config = Config::new().with_width(10).with_si_labels("B", 2);
+        let chart = plot_with_config(&data, config).unwrap();
+        let axis_columns: Vec<usize> = chart
+            .lines()
+            .map(|l| l.chars().position(|c| c == '│').unwrap())
+            .collect();
+        assert!(axis_columns.iter().all(|&c| c == axis_columns[0]));
+        assert!(chart.contains("1.05 MB"));
+        assert!(chart.contains("838.86 kB"));
+    }
+
+    #[test]
+    fn test_nice_step() {
+        assert_eq!(nice_step(10.0, 5), 2.0);
+        assert_eq!(nice_step(7.0, 5), 2.0);
+        assert_eq!(nice_step(100.0, 4), 50.0);
+        assert_eq!(nice_step(0.9, 3), 0.5);
+        assert!((nice_step(0.03, 5) - 0.01).abs() < 1e-12);
+        assert_eq!(nice_ticks(0.3, 9.4, 5), Some(vec![2.0, 4.0, 6.0, 8.0]));
+        assert_eq!(nice_ticks(5.0, 1.0, 5), None);
+        assert_eq!(nice_ticks(-1e308, 1e308, 5), None);
+    }
+
+    #[test]
+    fn test_nice_ticks_fall_back_to_rows() {
+        let config = Config::new().with_height(4).with_width(10).with_nice_ticks(true);
+        let chart = plot_with_config(&[-1e308, 1e308], config.clone()).unwrap();
+        assert!(!chart.contains("NaN"));
+        assert!(chart.lines().next().unwrap().trim_start().starts_with("100000"));
+
+        // A one-sided min above the data inverts the range
+        let chart = plot_with_config(&[1.0, 2.0], config.with_min(5.0)).unwrap();
+        assert!(!chart.contains("NaN"));
+        assert!(chart.starts_with("2.00│"));
+    }
+
+    #[test]
+    fn test_nice_tick_labels() {
+        let data = vec![0.0, 10.0];
+        let config = Config::new()
+            .with_height(10)
+            .with_width(10)
+            .with_label_format("{:.0}".to_string())
+            .with_nice_ticks(false);
+        let chart = plot_with_config(&data, config).unwrap();
+        let labels: Vec<&str> = chart.lines().map(|l| l.split('│').next().unwrap().trim()).collect();
+        assert_eq!(labels, vec!["10", "", "8", "", "6", "", "4", "", "2", "", "0"]);
+    }
+
+    #[test]
+    fn test_nice_bounds_extend_range() {
+        let data = vec![0.7, 9.3];
+        let config = Config::new()
+            .with_height(5)
+            .with_width(10)
+            .with_label_format("{:.1}".to_string())
+            .with_nice_ticks(true);
+        let chart = plot_with_config(&data, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert!(lines[0].starts_with("10.0│"));
+        assert!(lines[5].starts_with(" 0.0│"));
+
+        // Fixed bounds are left alone
+        let config = Config::new().with_min(0.7).with_nice_ticks(true);
+        let (min, max) = series_bounds(&[&data], &config).unwrap();
+        assert_eq!((min, max), (0.7, 10.0));
+    }
+
+    #[test]
+    fn test_log_scale_decade_labels() {
+        let data = vec![1.0, 10.0, 100.0, 1000.0];
+        let config = Config::new()
+            .with_height(6)
+            .with_width(10)
+            .with_label_format("{:.0}".to_string())
+            .with_log_scale();
+        let chart = plot_with_config(&data, config).unwrap();
+        let labels: Vec<&str> = chart.lines().map(|l| l.split('│').next().unwrap().trim()).collect();
+        assert_eq!(labels, vec!["1000", "", "100", "", "10", "", "1"]);
+        // Each decade climbs the same number of rows
+        assert_eq!(chart.lines().nth(2).unwrap(), " 100│  ╭╯     ");
+        assert_eq!(chart.lines().nth(4).unwrap(), "  10│ ╭╯      ");
+    }
+
+    #[test]
+    fn test_log_scale_non_positive() {
+        let data = vec![1.0, 0.0, 100.0];
+        let config = Config::new().with_log_scale();
+        assert_eq!(plot_with_config(&data, config.clone()), Err(ChartError::NonPositiveLogValue));
+        assert!(plot_with_config(&data, config.with_log_gaps(true)).is_ok());
+
+        assert_eq!(
+            Config::new().with_log_scale().with_min(0.0).validate(),
+            Err(ChartError::NonPositiveLogValue)
+        );
+        assert_eq!(Config::new().with_log_base(1.0).validate(), Err(ChartError::InvalidRange));
+        assert_eq!(Config::new().with_log_base(0.5).validate(), Err(ChartError::InvalidRange));
+    }
+
+    #[test]
+    fn test_log_scale_arbitrary_base() {
+        let data = vec![1.0, 2.0, 4.0, 8.0];
+        let config = Config::new()
+            .with_height(3)
+            .with_width(10)
+            .with_label_format("{:.0}".to_string())
+            .with_log_base(2.0);
+        let chart = plot_with_config(&data, config).unwrap();
+        let labels: Vec<&str> = chart.lines().map(|l| l.split('│').next().unwrap().trim()).collect();
+        assert_eq!(labels, vec!["8", "4", "2", "1"]);
+    }
+
+    #[test]
+    fn test_line_points() {
+        assert_eq!(line_points(0, 0, 3, 0), vec![(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(line_points(0, 3, 1, 0), vec![(0, 3), (0, 2), (1, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn test_braille_resolution() {
+        // 8 samples fit in 4 columns, 4 levels in one row
+        let data = vec![3.0, 2.0, 1.0, 0.0, 0.0, 1.0, 2.0, 3.0];
+        let config = Config::new()
+            .with_height(1)
+            .with_width(5)
+            .with_labels(false)
+            .with_render_mode(RenderMode::Braille);
+        let chart = plot_with_config(&data, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|l| l.chars().count() == 5));
+        assert!(lines.iter().all(|l| l.chars().skip(1).all(|c| c == ' ' || ('\u{2800}'..='\u{28FF}').contains(&c))));
+        // Max sits mid-way down the top row, min mid-way down the bottom row
+        assert_eq!(lines[0], " ⢄  ⡠");
+        assert_eq!(lines[1], "  ⠱⠜ ");
+    }
+
+    #[test]
+    fn test_braille_labels_and_axis() {
+        let data: Vec<f64> = (0..20).map(|x| x as f64).collect();
+        let config = Config::new()
+            .with_height(4)
+            .with_width(12)
+            .with_label_format("{:.0}".to_string())
+            .with_x_axis(true)
+            .with_render_mode(RenderMode::Braille);
+        let chart = plot_with_config(&data, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert!(lines[0].starts_with("19│"));
+        assert!(lines[4].starts_with(" 0│"));
+        assert_eq!(lines[5], "  └┴────┴─────");
+        assert_eq!(lines[6], "   0    10");
+    }
+
+    #[test]
+    fn test_half_block_line() {
+        let data = vec![0.0, 1.0, 2.0, 3.0];
+        let config = Config::new()
+            .with_height(1)
+            .with_width(5)
+            .with_labels(false)
+            .with_render_mode(RenderMode::HalfBlock);
+        let chart = plot_with_config(&data, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        // Four values climb one half row per column
+        assert_eq!(lines, vec!["   ▄▀", " ▄▀  "]);
+    }
+
+    #[test]
+    fn test_half_block_filled_aligns_with_labels() {
+        let data = vec![0.0, 5.0, 10.0];
+        let config = Config::new()
+            .with_height(2)
+            .with_width(4)
+            .with_label_ticks(2)
+            .with_label_format("{:.0}".to_string())
+            .with_render_mode(RenderMode::HalfBlockFilled);
+        let chart = plot_with_config(&data, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines, vec!["10│  █", " 5│ ▄█", " 0│▄██"]);
+    }
+
+    #[test]
+    fn test_sparkline_levels() {
+        assert_eq!(sparkline(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]), "▁▂▃▄▅▆▇█");
+        assert_eq!(sparkline(&[3.0, 3.0, 3.0]), "▁▁▁");
+        assert_eq!(sparkline_ascii(&[0.0, 7.0]), "_#");
+        assert_eq!(sparkline(&[]), "Cannot plot empty data");
+    }
+
+    #[test]
+    fn test_sparkline_gaps_and_fixed_range() {
+        let data = vec![0.0, f64::NAN, 50.0, f64::INFINITY, 200.0];
+        let config = SparklineConfig::new().with_min(0.0).with_max(100.0).with_gap('·');
+        assert_eq!(sparkline_with_config(&data, config).unwrap(), "▁·▅·█");
+
+        let config = SparklineConfig::new().with_min(5.0).with_max(1.0);
+        assert_eq!(sparkline_with_config(&data, config), Err(ChartError::InvalidRange));
+    }
+
+    #[test]
+    fn test_sparkline_width() {
+        let long: Vec<f64> = (0..80).map(|x| x as f64).collect();
+        let config = SparklineConfig::new().with_width(8);
+        assert_eq!(sparkline_with_config(&long, config).unwrap(), "▁▂▃▄▅▆▇█");
+
+        let config = SparklineConfig::new().with_width(6);
+        assert_eq!(sparkline_with_config(&[1.0, 2.0], config).unwrap(), "▁█    ");
+
+        let gappy = vec![f64::NAN, f64::NAN, 1.0, 2.0];
+        let config = SparklineConfig::new().with_width(2).with_gap('_');
+        // Buckets are scaled against the full series range
+        assert_eq!(sparkline_with_config(&gappy, config).unwrap(), "_▅");
+    }
+
+    #[test]
+    fn test_bar_chart_partial_blocks() {
+        let data = vec![4.0, 2.0, 1.0];
+        let config = Config::new()
+            .with_height(1)
+            .with_width(8)
+            .with_label_format("{:.0}".to_string());
+        let chart = bar_chart_with_config(&data, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        // Bars end in the middle of their label's row: 4.0 reaches halfway into
+        // the top row and 1.0, a quarter row above 0, is six eighths tall
+        assert_eq!(lines, vec!["4│▄      ", "0│█ █ ▆  "]);
+    }
+
+    #[test]
+    fn test_bar_chart_negative_and_width() {
+        let data = vec![2.0, -2.0];
+        let config = Config::new()
+            .with_height(3)
+            .with_width(8)
+            .with_labels(false)
+            .with_bar_width(2)
+            .with_bar_gap(1);
+        let chart = bar_chart_with_config(&data, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines, vec![" ▄▄     ", " ██     ", "    ██  ", "    ▀▀  "]);
+    }
+
+    #[test]
+    fn test_bar_chart_fixed_min_above_data() {
+        let config = Config::new().with_width(8).with_height(3).with_min(5.0);
+        assert_eq!(bar_chart_with_config(&[1.0, 3.0], config), Err(ChartError::InvalidRange));
+    }
+
+    #[test]
+    fn test_bar_chart_ascii_and_errors() {
+        let data = vec![1.0, 2.0];
+        let config = Config::new()
+            .with_height(1)
+            .with_width(5)
+            .with_labels(false)
+            .with_ascii_symbols();
+        let chart = bar_chart_with_config(&data, config).unwrap();
+        assert_eq!(chart, "   # \n # # ");
+
+        assert_eq!(bar_chart_with_config(&[], Config::new()), Err(ChartError::EmptyData));
+        assert_eq!(
+            bar_chart_with_config(&data, Config::new().with_bar_width(0)),
+            Err(ChartError::InvalidDimensions)
+        );
+    }
+
+    #[test]
+    fn test_hbar_chart_layout() {
+        let items = [("a", 2.0), ("bbb", 4.0), ("cc", 1.0)];
+        let config = Config::new()
+            .with_width(15)
+            .with_label_format("{:.1}".to_string());
+        let chart = hbar_chart_with_config(&items, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        // 15 columns - 3 (name) - 1 (axis) - 1 (space) - 3 (value) = 7 for bars
+        assert_eq!(lines, vec!["a  │███▌ 2.0", "bbb│███████ 4.0", "cc │█▊ 1.0"]);
+    }
 
-            // let line: String = row.iter().collect();
-            // label + │ + chart, x=0 is always space so no double │
-            // lines.push(format!("{}│{}", label, &line[1..]));
-            // Make sure the line starts from column 1 (chart starts here)
-            // let chart_part: String = row[1..].iter().collect();
-            // lines.push(format!("{}{}{}", label, config.symbols.axis_vertical, chart_part));
+    #[test]
+    fn test_hbar_chart_sort_truncate_ascii() {
+        let items = [("short", 1.0), ("a very long endpoint", 3.0), ("mid", f64::NAN)];
+        let config = Config::new()
+            .with_width(20)
+            .with_ascii_symbols()
+            .with_label_format("{:.0}".to_string())
+            .with_category_width(6)
+            .with_bar_sort(BarSort::Descending);
+        let chart = hbar_chart_with_config(&items, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines, vec!["a ve..|######### 3", "short |### 1", "mid   | NaN"]);
 
-            let mut chart_part = row[1..].to_vec();
+        let config = Config::new().with_width(6);
+        assert_eq!(hbar_chart_with_config(&items, config), Err(ChartError::InvalidDimensions));
+    }
 
-            // If the first chart row is vertical ('│'), replace it so that it is not double axis
-            if chart_part.first() == Some(&config.symbols.axis_vertical) {
-                chart_part[0] = ' '; // atau hapus: chart_part.remove(0);
-            }
+    #[test]
+    fn test_histogram_strategies() {
+        let samples: Vec<f64> = (0..16).map(|x| x as f64).collect();
 
-            let chart_str: String = chart_part.iter().collect();
-            
-            lines.push(format!("{}{}{}", label, config.symbols.axis_vertical, chart_str));
+        let hist = Histogram::new(&samples, BinStrategy::Count(4)).unwrap();
+        assert_eq!(hist.edges, vec![0.0, 3.75, 7.5, 11.25, 15.0]);
+        assert_eq!(hist.counts, vec![4, 4, 4, 4]);
 
-        }
-    } else {
-        for row in canvas.iter() {
-            let line: String = row.iter().collect();
-            lines.push(line);
-        }
-    }
+        let hist = Histogram::new(&samples, BinStrategy::Width(5.0)).unwrap();
+        assert_eq!(hist.edges, vec![0.0, 5.0, 10.0, 15.0]);
+        assert_eq!(hist.counts, vec![5, 5, 6]);
 
-    Ok(lines.join("\n"))
-}
+        // log2(16) + 1 = 5 bins
+        assert_eq!(Histogram::new(&samples, BinStrategy::Sturges).unwrap().counts.len(), 5);
 
-/// Format a value according to the format string
-fn format_value(value: f64, format: &str) -> String {
-    // Simple implementation - extend as needed
-    if format.contains(":.2") {
-        format!("{:.2}", value)
-    } else if format.contains(":.1") {
-        format!("{:.1}", value)
-    } else if format.contains(":.0") {
-        format!("{:.0}", value)
-    } else {
-        format!("{:.2}", value)
-    }
-}
+        // IQR = 7.5, width = 15 / 16^(1/3) ≈ 5.95, so 3 bins span 0..15
+        assert_eq!(Histogram::new(&samples, BinStrategy::FreedmanDiaconis).unwrap().counts.len(), 3);
 
-// ============================================================================
-// Helper Functions
-// ============================================================================
+        assert_eq!(Histogram::new(&samples, BinStrategy::Count(0)), Err(ChartError::InvalidDimensions));
+        assert_eq!(Histogram::new(&samples, BinStrategy::Count(usize::MAX)), Err(ChartError::InvalidDimensions));
+        assert_eq!(Histogram::new(&[0.0, 1e9], BinStrategy::Width(1e-3)), Err(ChartError::InvalidDimensions));
+        assert_eq!(Histogram::new(&[-f64::MAX, f64::MAX], BinStrategy::Sturges), Err(ChartError::InvalidRange));
+        assert_eq!(Histogram::new(&samples, BinStrategy::Width(-1.0)), Err(ChartError::InvalidRange));
+        assert_eq!(Histogram::new(&[f64::NAN], BinStrategy::Sturges), Err(ChartError::EmptyData));
+    }
 
-/// Simple plot function with default config
-///
-/// # Example
-///
-/// ```rust
-/// use rasciichart::plot;
-///
-/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 4.0, 3.0, 2.0, 1.0];
-/// println!("{}", plot(&data));
-/// ```
-pub fn plot(series: &[f64]) -> String {
-    plot_with_config(series, Config::default()).unwrap_or_else(|e| e.to_string())
-}
+    #[test]
+    fn test_histogram_single_value_and_discarded() {
+        let hist = Histogram::new(&[2.0, 2.0, f64::NAN, f64::INFINITY], BinStrategy::Sturges).unwrap();
+        assert_eq!(hist.edges, vec![1.5, 2.5]);
+        assert_eq!(hist.counts, vec![2]);
+        assert_eq!(hist.discarded, 2);
+    }
 
-/// Plot with custom height and width
-///
-/// # Example
-///
-/// ```rust
-/// use rasciichart::plot_sized;
-///
-/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-/// println!("{}", plot_sized(&data, 15, 60));
-/// ```
-pub fn plot_sized(series: &[f64], height: usize, width: usize) -> String {
-    plot_with_config(
-        series,
-        Config::default().with_height(height).with_width(width)
-    ).unwrap_or_else(|e| e.to_string())
-}
+    #[test]
+    fn test_histogram_render() {
+        let samples = vec![1.0, 2.0, 2.0, 3.0, 4.0, 4.0, 4.0, 4.0, f64::NAN];
+        let hist = Histogram::new(&samples, BinStrategy::Count(3)).unwrap();
+        assert_eq!(hist.counts, vec![1, 2, 5]);
 
-/// Plot without Y-axis labels
-///
-/// # Example
-///
-/// ```rust
-/// use rasciichart::plot_no_labels;
-///
-/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-/// println!("{}", plot_no_labels(&data));
-/// ```
-pub fn plot_no_labels(series: &[f64]) -> String {
-    plot_with_config(
-        series,
-        Config::default().with_labels(false)
-    ).unwrap_or_else(|e| e.to_string())
-}
+        let config = Config::new()
+            .with_height(3)
+            .with_width(10)
+            .with_label_format("{:.0}".to_string());
+        let vertical = hist.render(Orientation::Vertical, config.clone()).unwrap();
+        let lines: Vec<&str> = vertical.lines().collect();
+        assert_eq!(lines[0], "5│    ▄    ");
+        assert_eq!(lines[3], "0│█ █ █    ");
+        // The final edge would touch the last left edge, so it is skipped
+        assert_eq!(lines[4], "  1 2 3");
+        assert_eq!(lines[5], "1 non-finite sample discarded");
 
-/// Plot with custom min and max values
-///
-/// # Example
-///
-/// ```rust
-/// use rasciichart::plot_range;
-///
-/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-/// println!("{}", plot_range(&data, 0.0, 10.0));
-/// ```
-pub fn plot_range(series: &[f64], min: f64, max: f64) -> String {
-    plot_with_config(
-        series,
-        Config::default().with_min(min).with_max(max)
-    ).unwrap_or_else(|e| e.to_string())
-}
+        let horizontal = hist.render(Orientation::Horizontal, config.clone().with_width(20)).unwrap();
+        let lines: Vec<&str> = horizontal.lines().collect();
+        assert_eq!(lines[0], "[1, 2)│██▎ 1");
+        assert_eq!(lines[2], "[3, 4]│███████████ 5");
 
-/// Plot using ASCII-only characters for better compatibility
-///
-/// # Example
-///
-/// ```rust
-/// use rasciichart::plot_ascii;
-///
-/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-/// println!("{}", plot_ascii(&data));
-/// ```
-pub fn plot_ascii(series: &[f64]) -> String {
-    plot_with_config(
-        series,
-        Config::default().with_ascii_symbols()
-    ).unwrap_or_else(|e| e.to_string())
-}
+        // A value range meant for the samples does not clip the counts
+        let ranged = hist.render(Orientation::Horizontal, config.with_width(20).with_min(1.0).with_max(4.0));
+        assert_eq!(ranged.unwrap(), horizontal);
+    }
 
-/// Plot multiple series on the same chart (overlaid)
-///
-/// # Example
-///
-/// ```rust
-/// use rasciichart::plot_multiple;
-///
-/// let series1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-/// let series2 = vec![5.0, 4.0, 3.0, 2.0, 1.0];
-/// println!("{}", plot_multiple(&[&series1, &series2]));
-/// ```
-pub fn plot_multiple(series: &[&[f64]]) -> String {
-    if series.is_empty() {
-        return "No data".to_string();
+    #[test]
+    fn test_candlestick_glyphs() {
+        let candles = [(2.0, 4.0, 0.0, 3.0), (3.0, 3.0, 1.0, 1.0)];
+        let config = Config::new()
+            .with_height(4)
+            .with_width(6)
+            .with_labels(false)
+            .with_bar_gap(1);
+        let chart = candlestick_with_config(&candles, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines, vec![" │    ", " ┃ ┃  ", " ┃ ┃  ", " │ ┃  ", " │    "]);
     }
 
-    // Find global min and max
-    let mut global_min = f64::INFINITY;
-    let mut global_max = f64::NEG_INFINITY;
+    #[test]
+    fn test_candlestick_colors_and_labels() {
+        let candles = [(1.0, 2.0, 0.0, 2.0), (2.0, 2.0, 0.0, 0.0)];
+        let config = Config::new()
+            .with_height(2)
+            .with_width(5)
+            .with_label_format("{:.0}".to_string())
+            .with_candle_colors(Color::Green, Color::Red);
+        let chart = candlestick_with_config(&candles, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert!(lines[0].starts_with("2│\x1b[32m┃\x1b[0m \x1b[31m┃\x1b[0m"));
+        assert!(lines[2].starts_with("0│"));
 
-    for s in series {
-        for &val in *s {
-            if val.is_finite() {
-                global_min = global_min.min(val);
-                global_max = global_max.max(val);
-            }
-        }
+        // The first candle's wicks survive next to the axis
+        let candles = [(1.0, 4.0, 0.0, 3.0), (3.0, 4.0, 0.0, 1.0)];
+        let config = Config::new().with_height(4).with_width(5).with_label_format("{:.0}".to_string());
+        let chart = candlestick_with_config(&candles, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines, vec!["4││ │ ", " │┃ ┃ ", " │┃ ┃ ", " │┃ ┃ ", "0││ │ "]);
+
+        let bad = [(1.0, 0.5, 0.0, 0.8)];
+        assert_eq!(candlestick_with_config(&bad, Config::new()), Err(ChartError::InvalidRange));
     }
 
-    if !global_min.is_finite() || !global_max.is_finite() {
-        return "Invalid data".to_string();
+    #[test]
+    fn test_scatter_markers_and_axes() {
+        let points = [(0.0, 0.0), (1.0, 1.0), (2.0, 4.0), (3.0, 9.0)];
+        let config = Config::new()
+            .with_height(4)
+            .with_width(13)
+            .with_label_format("{:.0}".to_string())
+            .with_x_label_interval(4);
+        let chart = scatter_with_config(&points, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines[0], "9│           ●");
+        assert_eq!(lines[4], "0│●   ●       ");
+        assert_eq!(lines[5], " └┴───┴──┴───┴");
+        assert_eq!(lines[6], "  0   1  2   3");
     }
 
-    // Plot first series with global min/max
-    let config = Config::default()
-        .with_min(global_min)
-        .with_max(global_max);
+    #[test]
+    fn test_scatter_fixed_ranges_and_modes() {
+        let points = [(0.0, 0.0), (5.0, 5.0), (20.0, 5.0), (f64::NAN, 1.0)];
+        let config = Config::new()
+            .with_height(2)
+            .with_width(11)
+            .with_labels(false)
+            .with_x_min(0.0)
+            .with_x_max(10.0)
+            .with_marker('x');
+        let chart = scatter_with_config(&points, config.clone()).unwrap();
+        assert_eq!(chart.matches('x').count(), 2);
 
-    plot_with_config(series[0], config).unwrap_or_else(|e| e.to_string())
-}
+        let braille = scatter_with_config(&points, config.with_render_mode(RenderMode::Braille)).unwrap();
+        assert!(braille.chars().any(|c| ('\u{2801}'..='\u{28FF}').contains(&c)));
 
-/// Generate sine wave data for testing
-///
-/// # Example
-///
-/// ```rust
-/// use rasciichart::{generate_sine, plot};
-///
-/// let data = generate_sine(50, 1.0, 0.0);
-/// println!("{}", plot(&data));
-/// ```
-pub fn generate_sine(points: usize, frequency: f64, phase: f64) -> Vec<f64> {
-    (0..points)
-        .map(|i| {
-            let x = i as f64 * 2.0 * std::f64::consts::PI / points as f64;
-            (frequency * x + phase).sin()
-        })
-        .collect()
-}
+        let bad = Config::new().with_x_min(1.0).with_x_max(1.0);
+        assert_eq!(scatter_with_config(&points, bad), Err(ChartError::InvalidRange));
+        assert_eq!(scatter_with_config(&[], Config::new()), Err(ChartError::EmptyData));
+    }
 
-/// Generate cosine wave data for testing
-pub fn generate_cosine(points: usize, frequency: f64, phase: f64) -> Vec<f64> {
-    (0..points)
-        .map(|i| {
-            let x = i as f64 * 2.0 * std::f64::consts::PI / points as f64;
-            (frequency * x + phase).cos()
-        })
-        .collect()
-}
+    #[test]
+    fn test_area_fill() {
+        let cpu = [20.0, 35.0, 50.0, 45.0, 70.0, 85.0, 60.0, 40.0, 30.0, 55.0];
+        let config = Config::new()
+            .with_height(6)
+            .with_width(12)
+            .with_labels(false)
+            .with_area(AreaBaseline::Min)
+            .with_fill_char('▒');
+        let chart = plot_with_config(&cpu, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines[2], "     │▒╰╮   ");
+        assert_eq!(lines[6], " ─╯▒▒▒▒▒▒▒▒ ");
 
-/// Generate random walk data for testing
-pub fn generate_random_walk(points: usize, start: f64, volatility: f64) -> Vec<f64> {
-    use std::collections::hash_map::RandomState;
-    use std::hash::{BuildHasher, Hash, Hasher};
-    
-    let mut result = Vec::with_capacity(points);
-    let mut current = start;
-    result.push(current);
-    
-    for i in 1..points {
-        // Simple pseudo-random using hash
-        let s = RandomState::new();
-        let mut hasher = s.build_hasher();
-        i.hash(&mut hasher);
-        let hash = hasher.finish();
-        let random = (hash % 1000) as f64 / 1000.0 - 0.5;
-        
-        current += random * volatility;
-        result.push(current);
+        // A zero baseline fills up to negative values too
+        let config = Config::new()
+            .with_height(2)
+            .with_width(4)
+            .with_labels(false)
+            .with_area(AreaBaseline::Zero);
+        let chart = plot_with_config(&[1.0, -1.0, 1.0], config).unwrap();
+        assert_eq!(chart, " ─╮╭\n ░││\n  ╰╯");
     }
-    
-    result
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_area_follows_steps_and_inverted_range() {
+        // Step-before rises in the first column, so it fills from the value it steps to
+        let filled = |config: Config| {
+            let mut canvas = vec![vec![Cell::BLANK; 4]; 3];
+            draw_area(&mut canvas, &[0.0, 2.0, 1.0], 0, 0.0, 2.0, &config);
+            canvas.iter().map(|row| row[1].kind == CellKind::Fill).collect::<Vec<bool>>()
+        };
+        let config = Config::new().with_height(2).with_width(4).with_area(AreaBaseline::Min);
+        assert_eq!(filled(config.clone()), vec![false, false, false]);
+        assert_eq!(filled(config.with_step(StepMode::Before)), vec![false, true, true]);
+
+        // A fixed max below the data no longer panics; every point is clipped
+        let config = Config::new()
+            .with_width(10)
+            .with_height(4)
+            .with_max(0.5)
+            .with_area(AreaBaseline::Zero)
+            .with_clip_report(true);
+        let chart = plot_with_config(&[1.0, 5.0, 2.0, 8.0, 3.0], config).unwrap();
+        assert!(chart.ends_with("5 points outside range (▲ 5 above)"));
+    }
 
     #[test]
-    fn test_simple_plot() {
-        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 4.0, 3.0, 2.0, 1.0];
-        let chart = plot(&data);
-        assert!(!chart.is_empty());
+    fn test_area_dim_fill() {
+        let config = Config::new()
+            .with_height(2)
+            .with_width(4)
+            .with_labels(false)
+            .with_area(AreaBaseline::Min)
+            .with_series_styles(vec![Style::new().fg(Color::Green).bold()])
+            .with_dim_fill(true);
+        let chart = plot_with_config(&[1.0, 3.0, 2.0], config).unwrap();
+        assert!(chart.contains("\x1b[2;32m░\x1b[0m"));
+        assert!(chart.contains("\x1b[1;32m│╰\x1b[0m"));
     }
 
     #[test]
-    fn test_empty_data() {
-        let data: Vec<f64> = vec![];
-        let result = plot_with_config(&data, Config::default());
-        assert!(result.is_err());
+    fn test_step_modes() {
+        let data = [1.0, 1.0, 3.0, 3.0, 2.0, 2.0, 4.0, 1.0];
+        let step = |mode| {
+            let config = Config::new().with_height(3).with_width(9).with_labels(false).with_step(mode);
+            plot_with_config(&data, config).unwrap()
+        };
+
+        assert_eq!(step(StepMode::Before), "      ╭╮ \n  ╭─╮ ││ \n  │ ╰─╯│ \n ─╯    ╰─");
+        assert_eq!(step(StepMode::After), "       ╭╮\n   ╭─╮ ││\n   │ ╰─╯│\n ──╯    ╰");
+        // Adjacent samples leave no room for a middle column, so Mid matches After
+        assert_eq!(step(StepMode::Mid), step(StepMode::After));
     }
 
     #[test]
-    fn test_single_value() {
-        let data = vec![5.0];
-        let chart = plot(&data);
-        assert!(chart.contains("5.00"));
+    fn test_step_before_riser_next_to_axis() {
+        let config = Config::new()
+            .with_height(3)
+            .with_width(6)
+            .with_label_format("{:.0}".to_string())
+            .with_step(StepMode::Before);
+        let chart = plot_with_config(&[1.0, 4.0, 4.0, 4.0], config).unwrap();
+        assert_eq!(chart, "4│╭─── \n ││    \n ││    \n1│╯    ");
     }
 
     #[test]
-    fn test_custom_config() {
-        let data = vec![10.0, 20.0, 30.0, 20.0, 10.0];
-        let config = Config::default()
-            .with_height(15)
-            .with_width(50);
-        let chart = plot_with_config(&data, config).unwrap();
-        assert!(!chart.is_empty());
+    fn test_step_mid_between_distant_samples() {
+        let config = Config::new()
+            .with_height(3)
+            .with_width(6)
+            .with_labels(false)
+            .with_step(StepMode::Mid)
+            .with_gap_policy(GapPolicy::Bridge);
+        let chart = plot_with_config(&[1.0, f64::NAN, f64::NAN, 4.0], config).unwrap();
+        assert_eq!(chart, "   ╭─ \n   │  \n   │  \n ─┄╯  ");
     }
 
     #[test]
-    fn test_no_labels() {
-        let data = vec![1.0, 2.0, 3.0];
-        let chart = plot_no_labels(&data);
-        assert!(!chart.is_empty());
-        assert!(!chart.contains("│"));
+    fn test_downsample_strategies() {
+        let data = [0.0, 1.0, 9.0, 2.0, 3.0, -5.0, 4.0, 5.0, 6.0, 7.0, 8.0, 2.0];
+        let len = data.len();
+
+        assert_eq!(Downsample::EveryNth.apply(&data, len, 4), vec![(0, 0.0), (3, 2.0), (6, 4.0), (9, 7.0)]);
+        let means: Vec<f64> = Downsample::Mean.apply(&data, len, 4).iter().map(|p| p.1).collect();
+        assert!((means[0] - 10.0 / 3.0).abs() < 1e-9 && means[1] == 0.0 && means[2] == 5.0);
+        assert_eq!(Downsample::MinMax.apply(&data, len, 4), vec![(2, 9.0), (5, -5.0), (10, 8.0), (11, 2.0)]);
+        assert_eq!(Downsample::Lttb.apply(&data, len, 4), vec![(0, 0.0), (5, -5.0), (6, 4.0), (11, 2.0)]);
+
+        // Shorter series keep the bucket layout of the longest one
+        assert_eq!(Downsample::Mean.apply(&data[..5], len, 4), vec![(0, 10.0 / 3.0), (3, 2.5)]);
+        let gaps = Downsample::Mean.apply(&[f64::NAN, f64::NAN, 1.0, 1.0], 4, 2);
+        assert!(gaps[0].1.is_nan() && gaps[1].1 == 1.0);
     }
 
     #[test]
-    fn test_ascii_symbols() {
-        let data = vec![1.0, 2.0, 3.0];
-        let chart = plot_ascii(&data);
-        assert!(!chart.is_empty());
+    fn test_downsample_with_empty_series() {
+        let long: Vec<f64> = (0..100).map(|x| (x as f64 * 0.3).sin()).collect();
+        let empty: [f64; 0] = [];
+        for strategy in [Downsample::EveryNth, Downsample::Mean, Downsample::MinMax, Downsample::Lttb] {
+            assert!(strategy.apply(&empty, long.len(), 20).is_empty());
+            let config = Config::new().with_width(30).with_downsample(strategy);
+            assert!(plot_multiple_with_config(&[&long, &empty], config).is_ok());
+        }
     }
 
     #[test]
-    fn test_invalid_range() {
-        let config = Config::default().with_min(10.0).with_max(5.0);
-        assert!(config.validate().is_err());
+    fn test_downsample_keeps_spikes() {
+        let mut data = vec![0.0; 1000];
+        data[500] = 10.0;
+        let config = Config::new()
+            .with_height(4)
+            .with_width(21)
+            .with_label_format("{:.0}".to_string())
+            .with_x_axis(true);
+
+        // Truncated by default: the spike is never reached
+        let chart = plot_with_config(&data, config.clone()).unwrap();
+        assert_eq!(chart.lines().next(), Some("10│                    "));
+
+        let chart = plot_with_config(&data, config.with_downsample(Downsample::MinMax)).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines[0], "10│          ╭╮        ");
+        assert_eq!(lines[6], "   0         500");
     }
 
     #[test]
-    fn test_generate_sine() {
-        let data = generate_sine(50, 1.0, 0.0);
-        assert_eq!(data.len(), 50);
-        assert!(data[0].abs() < 0.1);
+    fn test_stretch_interpolation() {
+        let data = [0.0, 4.0, 2.0];
+
+        assert_eq!(stretch(&data, 3, 5, Interpolation::Nearest, None), vec![0.0, 4.0, 4.0, 2.0, 2.0]);
+        assert_eq!(stretch(&data, 3, 5, Interpolation::Linear, None), vec![0.0, 2.0, 4.0, 3.0, 2.0]);
+        assert_eq!(stretch(&data, 3, 5, Interpolation::Linear, Some(StepMode::After)), vec![0.0, 0.0, 4.0, 4.0, 2.0]);
+        assert_eq!(stretch(&data, 3, 5, Interpolation::Linear, Some(StepMode::Before)), vec![0.0, 4.0, 4.0, 2.0, 2.0]);
+
+        // Monotone cubic passes through the samples without overshooting the peak
+        let cubic = stretch(&data, 3, 9, Interpolation::MonotoneCubic, None);
+        assert_eq!((cubic[0], cubic[4], cubic[8]), (0.0, 4.0, 2.0));
+        assert!(cubic.iter().all(|&v| (0.0..=4.0).contains(&v)));
+        assert_eq!(monotone_tangents(&data), vec![4.0, 0.0, -2.0]);
+
+        // Shorter series keep the spacing of the longest one; gaps stay gaps
+        assert_eq!(stretch(&data[..2], 3, 5, Interpolation::Linear, None), vec![0.0, 2.0, 4.0]);
+        let gap = stretch(&[1.0, f64::NAN, 3.0], 3, 5, Interpolation::Linear, None);
+        assert!(gap[0] == 1.0 && gap[1..4].iter().all(|v| v.is_nan()) && gap[4] == 3.0);
+        let cubic = stretch(&[1.0, 2.0, f64::NAN, 4.0, 5.0], 5, 9, Interpolation::MonotoneCubic, None);
+        assert_eq!(cubic[1], 1.5);
+        assert_eq!(cubic[7], 4.5);
+        assert!(cubic[3..6].iter().all(|v| v.is_nan()));
+        assert_eq!(monotone_tangents(&[1.0, 2.0, f64::NAN, 4.0, 5.0])[1], 1.0);
     }
 
     #[test]
-    fn test_with_nan() {
-        let data = vec![1.0, 2.0, f64::NAN, 4.0, 5.0];
-        let chart = plot(&data);
-        assert!(!chart.is_empty());
+    fn test_stretch_fills_width() {
+        let config = Config::new()
+            .with_height(2)
+            .with_width(9)
+            .with_label_format("{:.0}".to_string())
+            .with_x_axis(true)
+            .with_x_label_interval(1)
+            .with_stretch(Interpolation::Linear);
+        let chart = plot_with_config(&[0.0, 2.0, 0.0], config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines, vec!["2│    ╭╮  ", " │  ╭─╯╰─╮", "0│ ─╯    ╰", " └┴───┴──┴", "  0   1  2"]);
     }
 
     #[test]
-    fn test_with_infinity() {
-        let data = vec![1.0, 2.0, f64::INFINITY, 4.0, 5.0];
-        let chart = plot(&data);
-        assert!(!chart.is_empty());
+    fn test_gap_policies() {
+        let data = vec![Some(1.0), Some(3.0), None, None, Some(3.0), Some(2.0)];
+        let chart = |policy| {
+            let config = Config::new().with_height(2).with_width(7).with_labels(false).with_gap_policy(policy);
+            plot_optional_with_config(&data, config).unwrap()
+        };
+
+        // The line no longer runs across the gap as if data were present
+        assert_eq!(chart(GapPolicy::Break), "  ╭  ─╮\n  │   ╰\n ─╯    ");
+        assert_eq!(chart(GapPolicy::Bridge), "  ╭┄┄─╮\n  │   ╰\n ─╯    ");
+        assert_eq!(chart(GapPolicy::Mark), "  ╭  ─╮\n  │   ╰\n ─╯××  ");
+
+        let nan: Vec<f64> = data.iter().map(|v| v.unwrap_or(f64::NAN)).collect();
+        let config = Config::new().with_height(2).with_width(7).with_labels(false);
+        assert_eq!(plot_with_config(&nan, config).unwrap(), chart(GapPolicy::Break));
     }
 
     #[test]
-    fn test_small_range() {
-        let data = vec![1.001, 1.002, 1.003, 1.002, 1.001];
-        let chart = plot(&data);
-        assert!(!chart.is_empty());
-        // Should not be excessively tall
-        let line_count = chart.lines().count();
-        assert!(line_count <= 15); // Default height + 1
+    fn test_gap_bridge_braille() {
+        let config = Config::new()
+            .with_height(1)
+            .with_width(4)
+            .with_labels(false)
+            .with_render_mode(RenderMode::Braille);
+        let data = [0.0, f64::NAN, f64::NAN, f64::NAN, 1.0, 0.0];
+        let broken = plot_with_config(&data, config.clone()).unwrap();
+        let bridged = plot_with_config(&data, config.with_gap_policy(GapPolicy::Bridge)).unwrap();
+        let dots = |chart: &str| {
+            chart.chars().map(|c| (c as u32).saturating_sub(BRAILLE_BASE).count_ones()).sum::<u32>()
+        };
+        assert!(dots(&bridged) > dots(&broken));
+        assert!(dots(&bridged) < dots(&broken) + 3);
     }
 
     #[test]
-    fn test_ascending_line() {
-        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-        let chart = plot(&data);
-        // Should contain ascending characters
-        assert!(chart.contains("╭") || chart.contains("╰"));
+    fn test_overflow_markers() {
+        let data = [20.0, 40.0, 500.0, 60.0, -10.0, 30.0, 120.0, 110.0, 50.0];
+        let config = Config::new()
+            .with_height(5)
+            .with_width(12)
+            .with_min(0.0)
+            .with_max(100.0)
+            .with_label_format("{:.0}".to_string());
+        let chart = plot_with_config(&data, config.clone().with_clip_report(true)).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines[0], "100│  ▲╮  ▲▲╮  ");
+        assert_eq!(lines[5], "  0│    ▼╯     ");
+        assert_eq!(lines[6], "4 points outside range (▲ 3 above, ▼ 1 below)");
+
+        // The markers stay without the report, which is off by default
+        let chart = plot_with_config(&data, config.with_ascii_symbols()).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0], "100|  ^+  ^^+  ");
+        assert_eq!(lines[5], "  0|    v+     ");
     }
 
     #[test]
-    fn test_descending_line() {
-        let data = vec![5.0, 4.0, 3.0, 2.0, 1.0];
-        let chart = plot(&data);
-        // Should contain descending characters
-        assert!(chart.contains("╮") || chart.contains("╯"));
+    fn test_no_clip_report_within_range() {
+        let config = Config::new().with_min(0.0).with_max(10.0).with_height(4).with_clip_report(true);
+        let chart = plot_with_config(&[1.0, 10.0, 0.0], config.clone()).unwrap();
+        assert!(!chart.contains("outside range"));
+
+        let chart = plot_with_config(&[50.0, 5.0], config).unwrap();
+        assert!(chart.ends_with("1 point outside range (▲ 1 above)"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_reference_lines() {
+        let data = [0.0, 4.0, 1.0, 2.0];
+        let config = Config::new()
+            .with_height(4)
+            .with_width(12)
+            .with_label_format("{:.0}".to_string())
+            .with_reference_line(ReferenceLine::new(3.0).with_label("SLO"))
+            .with_reference_line(ReferenceLine::new(1.0))
+            .with_reference_line(ReferenceLine::new(9.0).with_label("off"));
+        let chart = plot_with_config(&data, config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        // Labels take their columns out of the plot; lines sit behind the series
+        assert_eq!(
+            lines,
+            vec![
+                "4│ ╭╮        ",
+                " │╌││╌╌╌╌ SLO",
+                " │ ││╭       ",
+                " │╌│╰╯╌╌╌    ",
+                "0│ ╯         ",
+            ]
+        );
+        assert!(!chart.contains("off"));
+    }
+
+    #[test]
+    fn test_reference_line_style_and_fill() {
+        let config = Config::new()
+            .with_height(2)
+            .with_width(6)
+            .with_labels(false)
+            .with_area(AreaBaseline::Min)
+            .with_reference_line(ReferenceLine::new(1.0).with_style(Color::Red.into()));
+        let chart = plot_with_config(&[0.0, 2.0, 2.0, 0.0], config).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        // The reference line shows through the area fill
+        assert_eq!(lines[1], " \x1b[31m╌\x1b[0m│\x1b[31m╌\x1b[0m│\x1b[31m╌\x1b[0m");
+    }
+}